@@ -0,0 +1,1670 @@
+mod interact_cli;
+mod interact_config;
+
+use clap::Parser;
+use empty::empty_proxy::{self, EscrowContractProxy};
+use empty::{Offer, OfferStatus};
+use multiversx_sc_snippets::imports::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{Read, Write},
+    path::Path,
+};
+
+pub use interact_cli::{InteractCli, InteractCliCommand};
+pub use interact_config::{ChainType, Config};
+
+// Deploy edilen kontratın adresi bu dosyada saklanır
+const STATE_FILE: &str = "state.toml";
+// Kontrat kodu, interactor dizinine göre
+const CONTRACT_CODE_PATH: &str = "mxsc:../output/empty.mxsc.json";
+
+// Komut satırından çağrılan giriş noktası
+pub async fn empty_cli() {
+    env_logger::init();
+
+    let cli = InteractCli::parse();
+    let mut interact = ContractInteract::new(Config::load_config()).await;
+    match cli.command {
+        Some(InteractCliCommand::Deploy) => interact.deploy().await,
+        Some(InteractCliCommand::Upgrade) => interact.upgrade().await,
+        Some(InteractCliCommand::Create(args)) => {
+            let release_condition = args.condition_target.as_deref().map(|target| {
+                empty_proxy::ReleaseCondition {
+                    target: bech32(target).to_address().into(),
+                    view: ManagedBuffer::from(args.condition_view.as_deref().unwrap_or_default()),
+                    arguments: args.condition_args.iter().map(|arg| ManagedBuffer::from(hex_arg(arg))).collect(),
+                    expected: ManagedBuffer::from(hex_arg(&args.condition_expected)),
+                }
+            });
+            interact
+                .create(
+                    &bech32(&args.buyer),
+                    args.amount,
+                    args.memo.as_deref(),
+                    args.reference.as_deref(),
+                    args.terms_hash.as_deref(),
+                    args.min_recipient_trades,
+                    release_condition,
+                    args.usd_amount,
+                    args.stake,
+                )
+                .await
+        },
+        Some(InteractCliCommand::Accept(args)) => {
+            let swap = args
+                .swap_token
+                .as_deref()
+                .map(|token_out| (token_out, args.min_amount_out.unwrap_or_default()));
+            interact.accept_offer(args.offer_id, swap).await
+        },
+        Some(InteractCliCommand::Cancel(args)) => interact.cancel_offer(args.offer_id).await,
+        Some(InteractCliCommand::Release(args)) => interact.release_offer(args.offer_id).await,
+        Some(InteractCliCommand::SetDeliveryCall(args)) => {
+            let arguments = args
+                .args
+                .iter()
+                .map(|arg| hex_arg(arg))
+                .collect();
+            interact
+                .set_delivery_call(args.offer_id, args.gas_limit, &args.endpoint, arguments)
+                .await
+        },
+        Some(InteractCliCommand::RetryDelivery(args)) => interact.retry_delivery(args.offer_id).await,
+        Some(InteractCliCommand::TryRelease(args)) => interact.try_release(args.offer_id).await,
+        Some(InteractCliCommand::CreateAuction(args)) => {
+            let token = args.token.as_deref().map(|token| (token, args.nonce));
+            interact
+                .create_auction(
+                    parse_auction_kind(&args.kind),
+                    args.start_price,
+                    args.end_price,
+                    args.duration,
+                    args.reveal_duration,
+                    token,
+                    args.amount,
+                )
+                .await
+        },
+        Some(InteractCliCommand::Bid(args)) => interact.bid(args.offer_id, args.amount).await,
+        Some(InteractCliCommand::Buy(args)) => interact.buy(args.offer_id, args.amount).await,
+        Some(InteractCliCommand::CommitBid(args)) => {
+            interact
+                .commit_bid(args.offer_id, args.amount, &args.salt, args.deposit)
+                .await
+        },
+        Some(InteractCliCommand::RevealBid(args)) => {
+            interact
+                .reveal_bid(args.offer_id, args.amount, &args.salt)
+                .await
+        },
+        Some(InteractCliCommand::SettleAuction(args)) => interact.settle_auction(args.offer_id).await,
+        Some(InteractCliCommand::WithdrawRefund) => interact.withdraw_refund().await,
+        Some(InteractCliCommand::SetUnrevealedBidPenalty(args)) => {
+            interact.set_unrevealed_bid_penalty(args.penalty).await
+        },
+        Some(InteractCliCommand::CreateSellOffer(args)) => {
+            let token = args.token.as_deref().map(|token| (token, args.nonce));
+            interact
+                .create_sell_offer(token, args.amount, args.price, args.min_fill)
+                .await
+        },
+        Some(InteractCliCommand::AcceptPartial(args)) => {
+            interact
+                .accept_partial(args.offer_id, args.amount, args.payment)
+                .await
+        },
+        Some(InteractCliCommand::SetEgldWrapper(args)) => interact.set_egld_wrapper(&bech32(&args.address)).await,
+        Some(InteractCliCommand::SetSwapPair(args)) => {
+            interact
+                .set_swap_pair(&args.token_out, &bech32(&args.pair))
+                .await
+        },
+        Some(InteractCliCommand::RemoveSwapPair(args)) => interact.remove_swap_pair(&args.token).await,
+        Some(InteractCliCommand::AllowToken(args)) => interact.allow_token(&args.token).await,
+        Some(InteractCliCommand::RemoveAllowedToken(args)) => interact.remove_allowed_token(&args.token).await,
+        Some(InteractCliCommand::SetTokenLimits(args)) => {
+            interact
+                .set_token_limits(args.token.as_deref(), args.min_amount, args.max_amount)
+                .await
+        },
+        Some(InteractCliCommand::RemoveTokenLimits(args)) => interact.remove_token_limits(args.token.as_deref()).await,
+        Some(InteractCliCommand::SetPriceOracle(args)) => {
+            interact
+                .set_price_oracle(&bech32(&args.oracle), args.max_price_age)
+                .await
+        },
+        Some(InteractCliCommand::SetLiquidStaking(args)) => {
+            interact
+                .set_liquid_staking(&bech32(&args.address), args.recipient_yield_share)
+                .await
+        },
+        Some(InteractCliCommand::LastOfferId) => {
+            println!("Last offer id: {}", interact.last_offer_id().await)
+        },
+        Some(InteractCliCommand::Offer(args)) => match interact.offer(args.offer_id).await {
+            Some(offer) => print_offer(&offer),
+            None => println!("Offer {} not found", args.offer_id),
+        },
+        Some(InteractCliCommand::OfferByReference(args)) => {
+            match interact.offer_by_reference(&bech32(&args.creator), &args.reference).await {
+                Some(offer) => print_offer(&offer),
+                None => println!("No offer for reference {}", args.reference),
+            }
+        },
+        Some(InteractCliCommand::ActiveOffers) => print_offers(&interact.active_offers().await),
+        Some(InteractCliCommand::UserOffers(args)) => {
+            println!("Offer ids: {:?}", interact.user_offers(&bech32(&args.address)).await)
+        },
+        Some(InteractCliCommand::UserIncomingOffers(args)) => {
+            println!("Offer ids: {:?}", interact.user_incoming_offers(&bech32(&args.address)).await)
+        },
+        Some(InteractCliCommand::UserActiveOffers(args)) => {
+            print_offers(&interact.user_active_offers(&bech32(&args.address)).await)
+        },
+        Some(InteractCliCommand::UserIncomingActiveOffers(args)) => {
+            print_offers(&interact.user_incoming_active_offers(&bech32(&args.address)).await)
+        },
+        Some(InteractCliCommand::UserClosedOffers(args)) => print_offers(
+            &interact
+                .user_closed_offers(&bech32(&args.address), args.offset, args.limit)
+                .await,
+        ),
+        Some(InteractCliCommand::UserIncomingClosedOffers(args)) => print_offers(
+            &interact
+                .user_incoming_closed_offers(&bech32(&args.address), args.offset, args.limit)
+                .await,
+        ),
+        Some(InteractCliCommand::DeliveryCall(args)) => interact.delivery_call(args.offer_id).await,
+        Some(InteractCliCommand::ReleaseCondition(args)) => interact.release_condition(args.offer_id).await,
+        Some(InteractCliCommand::UsdAmount(args)) => {
+            println!("USD amount (cents): {}", interact.usd_amount(args.offer_id).await)
+        },
+        Some(InteractCliCommand::PriceOracle) => interact.price_oracle().await,
+        Some(InteractCliCommand::SwapRoutes) => interact.swap_routes().await,
+        Some(InteractCliCommand::TokenRestrictions) => interact.token_restrictions().await,
+        Some(InteractCliCommand::LiquidStaking) => interact.liquid_staking().await,
+        Some(InteractCliCommand::StakingPosition(args)) => interact.staking_position(args.offer_id).await,
+        Some(InteractCliCommand::Auction(args)) => interact.auction(args.offer_id).await,
+        Some(InteractCliCommand::SealedBids(args)) => interact.sealed_bids(args.offer_id).await,
+        Some(InteractCliCommand::PendingRefund(args)) => {
+            println!("Pending refund: {}", interact.pending_refund(&bech32(&args.address)).await)
+        },
+        Some(InteractCliCommand::DutchAuctionPrice(args)) => {
+            println!("Current price: {}", interact.dutch_auction_price(args.offer_id).await)
+        },
+        Some(InteractCliCommand::SellOffer(args)) => interact.sell_offer(args.offer_id).await,
+        Some(InteractCliCommand::FillPrice(args)) => {
+            println!("Fill price: {}", interact.fill_price(args.offer_id, args.amount).await)
+        },
+        Some(InteractCliCommand::ArchivedOffer(args)) => interact.archived_offer(args.offer_id).await,
+        Some(InteractCliCommand::ArchiveRetentionPeriod) => {
+            println!("Archive retention period: {}s", interact.archive_retention_period().await)
+        },
+        Some(InteractCliCommand::Stats) => interact.stats().await,
+        Some(InteractCliCommand::Reputation(args)) => interact.reputation(&bech32(&args.address)).await,
+        Some(InteractCliCommand::BlockedAddresses) => interact.blocked_addresses().await,
+        Some(InteractCliCommand::IsBlocked(args)) => {
+            println!("Blocked: {}", interact.is_address_blocked(&bech32(&args.address)).await)
+        },
+        Some(InteractCliCommand::RoleHolders(args)) => interact.role_holders(parse_role(&args.role)).await,
+        Some(InteractCliCommand::HasRole(args)) => println!(
+            "Has role: {}",
+            interact
+                .has_role(parse_role(&args.role), &bech32(&args.address))
+                .await
+        ),
+        Some(InteractCliCommand::PendingAdmins) => interact.pending_admins().await,
+        Some(InteractCliCommand::ErrorCatalogue) => interact.error_catalogue().await,
+        Some(InteractCliCommand::MigrationProgress) => interact.migration_progress().await,
+        None => {},
+    }
+}
+
+// Interactor çalıştırmaları arasında saklanan durum
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct State {
+    contract_address: Option<Bech32Address>,
+}
+
+impl State {
+    // Durumu dosyadan okur, dosya yoksa boş durumla başlar
+    pub fn load_state() -> Self {
+        if Path::new(STATE_FILE).exists() {
+            let mut file = std::fs::File::open(STATE_FILE).unwrap();
+            let mut content = String::new();
+            file.read_to_string(&mut content).unwrap();
+            toml::from_str(&content).unwrap()
+        } else {
+            Self::default()
+        }
+    }
+
+    pub fn set_address(&mut self, address: Bech32Address) {
+        self.contract_address = Some(address);
+    }
+
+    pub fn current_address(&self) -> &Bech32Address {
+        self.contract_address
+            .as_ref()
+            .expect("no known contract, deploy first")
+    }
+}
+
+impl Drop for State {
+    // Interactor kapanırken durum dosyaya yazılır
+    fn drop(&mut self) {
+        let mut file = std::fs::File::create(STATE_FILE).unwrap();
+        file.write_all(toml::to_string(self).unwrap().as_bytes())
+            .unwrap();
+    }
+}
+
+pub struct ContractInteract {
+    pub interactor: Interactor,
+    pub wallet_address: Address,
+    contract_code: BytesValue,
+    state: State,
+}
+
+impl ContractInteract {
+    pub async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(config.gateway_uri())
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+
+        interactor.set_current_dir_from_workspace("interactor");
+
+        // PEM dosyası verilmediyse simulator'da test cüzdanı kullanılır
+        let wallet = match &config.pem_path {
+            Some(pem_path) => Wallet::from_pem_file(pem_path).expect("invalid PEM file"),
+            None => {
+                assert!(
+                    config.use_chain_simulator(),
+                    "pem_path is required outside the chain simulator"
+                );
+                test_wallets::alice()
+            },
+        };
+        let wallet_address = interactor.register_wallet(wallet).await;
+
+        // Simulator'da işlemlerin işlenebilmesi için ilk epoch'a kadar blok üretilir
+        if config.use_chain_simulator() {
+            interactor.generate_blocks_until_epoch(1).await.unwrap();
+        }
+
+        let contract_code = BytesValue::interpret_from(
+            CONTRACT_CODE_PATH,
+            &InterpreterContext::default(),
+        );
+
+        ContractInteract {
+            interactor,
+            wallet_address,
+            contract_code,
+            state: State::load_state(),
+        }
+    }
+
+    // Testlerde farklı bir cüzdandan işlem göndermek için
+    pub async fn register_wallet(&mut self, wallet: Wallet) -> Address {
+        self.interactor.register_wallet(wallet).await
+    }
+
+    // Son deploy edilen kontratın adresi
+    pub fn contract_address(&self) -> &Bech32Address {
+        self.state.current_address()
+    }
+
+    pub async fn deploy(&mut self) {
+        let new_address = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .init()
+            .code(&self.contract_code)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewBech32Address)
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        self.state.set_address(new_address);
+    }
+
+    pub async fn upgrade(&mut self) {
+        self.interactor
+            .tx()
+            .to(self.state.current_address())
+            .from(&self.wallet_address)
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .upgrade()
+            .code(&self.contract_code)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .run()
+            .await;
+
+        println!("upgraded: {}", self.state.current_address());
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &mut self,
+        buyer: &Bech32Address,
+        amount: u128,
+        memo: Option<&str>,
+        reference: Option<&str>,
+        terms_hash: Option<&str>,
+        min_recipient_trades: Option<u64>,
+        release_condition: Option<empty_proxy::ReleaseCondition<StaticApi>>,
+        usd_amount: Option<u64>,
+        stake: bool,
+    ) {
+        let from = self.wallet_address.clone();
+        self.create_from(
+            &from,
+            buyer,
+            amount,
+            memo,
+            reference,
+            terms_hash,
+            min_recipient_trades,
+            release_condition,
+            usd_amount,
+            stake,
+        )
+        .await;
+    }
+
+    // İsteğe bağlı argümanlar sırayla okunduğu için sonraki bir argüman verildiyse öncekiler boş gönderilir
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_from(
+        &mut self,
+        from: &Address,
+        buyer: &Bech32Address,
+        amount: u128,
+        memo: Option<&str>,
+        reference: Option<&str>,
+        terms_hash: Option<&str>,
+        min_recipient_trades: Option<u64>,
+        release_condition: Option<empty_proxy::ReleaseCondition<StaticApi>>,
+        usd_amount: Option<u64>,
+        stake: bool,
+    ) {
+        let given = [
+            memo.is_some(),
+            reference.is_some(),
+            terms_hash.is_some(),
+            min_recipient_trades.is_some(),
+            release_condition.is_some(),
+            usd_amount.is_some(),
+            stake,
+        ];
+        let buffer_arg = |index: usize, value: Option<&str>| -> OptionalValue<ManagedBuffer<StaticApi>> {
+            if given[index..].iter().any(|is_given| *is_given) {
+                OptionalValue::Some(ManagedBuffer::from(value.unwrap_or_default()))
+            } else {
+                OptionalValue::None
+            }
+        };
+
+        self.interactor
+            .tx()
+            .from(from)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .create(
+                buyer,
+                buffer_arg(0, memo),
+                buffer_arg(1, reference),
+                buffer_arg(2, terms_hash),
+                // Boş gönderilen en az işlem sayısı 0 olarak okunur, yani şart koşulmaz
+                if given[3..].iter().any(|is_given| *is_given) {
+                    OptionalValue::Some(min_recipient_trades.unwrap_or_default())
+                } else {
+                    OptionalValue::None
+                },
+                if given[4..].iter().any(|is_given| *is_given) {
+                    OptionalValue::Some(release_condition)
+                } else {
+                    OptionalValue::None
+                },
+                // Boş gönderilen USD miktarı 0 olarak okunur, yani teklif EGLD cinsindendir
+                if given[5..].iter().any(|is_given| *is_given) {
+                    OptionalValue::Some(BigUint::<StaticApi>::from(usd_amount.unwrap_or_default()))
+                } else {
+                    OptionalValue::None
+                },
+                if stake {
+                    OptionalValue::Some(true)
+                } else {
+                    OptionalValue::None
+                },
+            )
+            .egld(BigUint::<StaticApi>::from(amount))
+            .run()
+            .await;
+
+        println!("created offer {}", self.last_offer_id().await);
+    }
+
+    // swap: ödemenin takas edileceği token ve kabul edilen en az çıktı miktarı
+    pub async fn accept_offer(&mut self, offer_id: u64, swap: Option<(&str, u128)>) {
+        let from = self.wallet_address.clone();
+        self.accept_offer_from(&from, offer_id, swap).await;
+    }
+
+    pub async fn accept_offer_from(&mut self, from: &Address, offer_id: u64, swap: Option<(&str, u128)>) {
+        let swap = swap.map(|(token_out, amount_out_min)| {
+            MultiValue2::from((
+                TokenIdentifier::<StaticApi>::from(token_out),
+                BigUint::<StaticApi>::from(amount_out_min),
+            ))
+        });
+
+        self.interactor
+            .tx()
+            .from(from)
+            .to(self.state.current_address())
+            .gas(100_000_000u64)
+            .typed(EscrowContractProxy)
+            .accept_offer(offer_id, OptionalValue::from(swap))
+            .run()
+            .await;
+
+        println!("accepted offer {offer_id}");
+    }
+
+    pub async fn cancel_offer(&mut self, offer_id: u64) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .cancel_offer(offer_id)
+            .run()
+            .await;
+
+        println!("cancelled offer {offer_id}");
+    }
+
+    pub async fn release_offer(&mut self, offer_id: u64) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .release_offer(offer_id)
+            .run()
+            .await;
+
+        println!("released offer {offer_id}");
+    }
+
+    pub async fn set_delivery_call(
+        &mut self,
+        offer_id: u64,
+        gas_limit: u64,
+        endpoint: &str,
+        arguments: Vec<Vec<u8>>,
+    ) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .set_delivery_call(offer_id, gas_limit, endpoint, MultiValueVec::from(arguments))
+            .run()
+            .await;
+
+        println!("delivery call set for offer {offer_id}");
+    }
+
+    // Teslimat çağrısı için alıcı kontrata ayrılan gas'e ek olarak callback gas'i gerekir
+    pub async fn retry_delivery(&mut self, offer_id: u64) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(60_000_000u64)
+            .typed(EscrowContractProxy)
+            .retry_delivery(offer_id)
+            .run()
+            .await;
+
+        println!("retried delivery of offer {offer_id}");
+    }
+
+    pub async fn try_release(&mut self, offer_id: u64) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(60_000_000u64)
+            .typed(EscrowContractProxy)
+            .try_release(offer_id)
+            .run()
+            .await;
+
+        println!("released offer {offer_id}");
+    }
+
+    // token: açık artırmaya çıkarılacak token ve nonce, verilmezse EGLD
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_auction(
+        &mut self,
+        kind: empty_proxy::AuctionKind,
+        start_price: u128,
+        end_price: u128,
+        duration: u64,
+        reveal_duration: Option<u64>,
+        token: Option<(&str, u64)>,
+        amount: u128,
+    ) {
+        let payment = match token {
+            Some((token, nonce)) => EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(token),
+                nonce,
+                BigUint::from(amount),
+            ),
+            None => EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, BigUint::from(amount)),
+        };
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .create_auction(
+                kind,
+                BigUint::from(start_price),
+                BigUint::from(end_price),
+                duration,
+                OptionalValue::from(reveal_duration),
+            )
+            .payment(payment)
+            .run()
+            .await;
+
+        println!("created auction {}", self.last_offer_id().await);
+    }
+
+    pub async fn bid(&mut self, offer_id: u64, amount: u128) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .bid(offer_id)
+            .egld(BigUint::<StaticApi>::from(amount))
+            .run()
+            .await;
+
+        println!("bid {amount} on offer {offer_id}");
+    }
+
+    pub async fn buy(&mut self, offer_id: u64, amount: u128) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .buy(offer_id)
+            .egld(BigUint::<StaticApi>::from(amount))
+            .run()
+            .await;
+
+        println!("bought offer {offer_id}");
+    }
+
+    // Hash kontratın computeBidHash view'ı ile hesaplanır, miktar ve salt işlemle zincire gönderilmez
+    // Sorgu yapılandırılan gateway'e gittiği için güvenilen bir gateway kullanılmalıdır
+    pub async fn commit_bid(&mut self, offer_id: u64, amount: u128, salt: &str, deposit: u128) {
+        let bid_hash = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .compute_bid_hash(&self.wallet_address, BigUint::<StaticApi>::from(amount), ManagedBuffer::from(salt))
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .commit_bid(offer_id, bid_hash)
+            .egld(BigUint::<StaticApi>::from(deposit))
+            .run()
+            .await;
+
+        println!("committed sealed bid on offer {offer_id}");
+    }
+
+    pub async fn reveal_bid(&mut self, offer_id: u64, amount: u128, salt: &str) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .reveal_bid(offer_id, BigUint::<StaticApi>::from(amount), ManagedBuffer::from(salt))
+            .run()
+            .await;
+
+        println!("revealed bid {amount} on offer {offer_id}");
+    }
+
+    pub async fn set_unrevealed_bid_penalty(&mut self, penalty: u32) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .set_unrevealed_bid_penalty(penalty)
+            .run()
+            .await;
+
+        println!("unrevealed bid penalty set to {penalty} bps");
+    }
+
+    pub async fn settle_auction(&mut self, offer_id: u64) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .settle_auction(offer_id)
+            .run()
+            .await;
+
+        println!("settled offer {offer_id}");
+    }
+
+    pub async fn withdraw_refund(&mut self) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .withdraw_refund()
+            .run()
+            .await;
+
+        println!("withdrew pending refund");
+    }
+
+    // token: satışa çıkarılacak token ve nonce, verilmezse EGLD
+    pub async fn create_sell_offer(&mut self, token: Option<(&str, u64)>, amount: u128, price: u128, min_fill: u128) {
+        let payment = match token {
+            Some((token, nonce)) => EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(token),
+                nonce,
+                BigUint::from(amount),
+            ),
+            None => EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, BigUint::from(amount)),
+        };
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .create_sell_offer(BigUint::<StaticApi>::from(price), BigUint::<StaticApi>::from(min_fill))
+            .payment(payment)
+            .run()
+            .await;
+
+        println!("created sell offer {}", self.last_offer_id().await);
+    }
+
+    // payment verilmezse alımın fiyatı kontrattan sorgulanır
+    pub async fn accept_partial(&mut self, offer_id: u64, amount: u128, payment: Option<u128>) {
+        let payment = match payment {
+            Some(payment) => BigUint::<StaticApi>::from(payment),
+            None => BigUint::from(self.fill_price(offer_id, amount).await),
+        };
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .accept_partial(offer_id, BigUint::<StaticApi>::from(amount))
+            .egld(payment)
+            .run()
+            .await;
+
+        println!("bought {amount} from offer {offer_id}");
+    }
+
+    pub async fn set_egld_wrapper(&mut self, wrapper: &Bech32Address) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .set_egld_wrapper(wrapper)
+            .run()
+            .await;
+
+        println!("EGLD wrapper set to {wrapper}");
+    }
+
+    pub async fn set_swap_pair(&mut self, token_out: &str, pair: &Bech32Address) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .set_swap_pair(TokenIdentifier::from(token_out), pair)
+            .run()
+            .await;
+
+        println!("swap pair for {token_out} set to {pair}");
+    }
+
+    pub async fn set_liquid_staking(&mut self, liquid_staking: &Bech32Address, recipient_yield_share: u32) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .set_liquid_staking(liquid_staking, recipient_yield_share)
+            .run()
+            .await;
+
+        println!("liquid staking set to {liquid_staking}, recipient yield share {recipient_yield_share} bps");
+    }
+
+    pub async fn remove_swap_pair(&mut self, token_out: &str) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .remove_swap_pair(TokenIdentifier::from(token_out))
+            .run()
+            .await;
+
+        println!("swap pair for {token_out} removed");
+    }
+
+    pub async fn allow_token(&mut self, token: &str) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .allow_token(TokenIdentifier::from(token))
+            .run()
+            .await;
+
+        println!("{token} allowed");
+    }
+
+    pub async fn remove_allowed_token(&mut self, token: &str) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .remove_allowed_token(TokenIdentifier::from(token))
+            .run()
+            .await;
+
+        println!("{token} removed from the allowed tokens");
+    }
+
+    // token verilmezse sınırlar EGLD için ayarlanır
+    pub async fn set_token_limits(&mut self, token: Option<&str>, min_amount: u128, max_amount: u128) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .set_token_limits(
+                token_or_egld(token),
+                BigUint::<StaticApi>::from(min_amount),
+                BigUint::<StaticApi>::from(max_amount),
+            )
+            .run()
+            .await;
+
+        println!("{} limits set to {min_amount}..{max_amount}", token.unwrap_or("EGLD"));
+    }
+
+    pub async fn remove_token_limits(&mut self, token: Option<&str>) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .remove_token_limits(token_or_egld(token))
+            .run()
+            .await;
+
+        println!("{} limits removed", token.unwrap_or("EGLD"));
+    }
+
+    pub async fn set_price_oracle(&mut self, oracle: &Bech32Address, max_price_age: u64) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .set_price_oracle(oracle, max_price_age)
+            .run()
+            .await;
+
+        println!("price oracle set to {oracle}");
+    }
+
+    // Hata beklenen çağrılar için - testlerde kullanılır
+    pub async fn accept_offer_expect_err(&mut self, from: &Address, offer_id: u64, expected: &str) {
+        self.interactor
+            .tx()
+            .from(from)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(EscrowContractProxy)
+            .accept_offer(offer_id, OptionalValue::<MultiValue2<TokenIdentifier<StaticApi>, BigUint<StaticApi>>>::None)
+            .returns(ExpectError(4, expected))
+            .run()
+            .await;
+    }
+
+    pub async fn last_offer_id(&mut self) -> u64 {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .last_offer_id()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    // Offer'ın TopDecode'u elle yazıldığı için teklif döndüren view'lar kontratın kendi tipiyle çözülür
+    pub async fn offer(&mut self, offer_id: u64) -> Option<Offer<StaticApi>> {
+        let raw = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_offer(offer_id)
+            .returns(ReturnsRawResult)
+            .run()
+            .await;
+
+        decode_offers(raw).pop()
+    }
+
+    pub async fn offer_by_reference(
+        &mut self,
+        creator: &Bech32Address,
+        reference: &str,
+    ) -> Option<Offer<StaticApi>> {
+        let raw = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_offer_by_reference(creator, ManagedBuffer::from(reference))
+            .returns(ReturnsRawResult)
+            .run()
+            .await;
+
+        decode_offers(raw).pop()
+    }
+
+    pub async fn active_offers(&mut self) -> Vec<Offer<StaticApi>> {
+        let raw = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_active_offers()
+            .returns(ReturnsRawResult)
+            .run()
+            .await;
+
+        decode_offers(raw)
+    }
+
+    pub async fn user_offers(&mut self, user: &Bech32Address) -> Vec<u64> {
+        let offer_ids = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .user_offers(user)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        offer_ids.into_iter().collect()
+    }
+
+    pub async fn user_incoming_offers(&mut self, user: &Bech32Address) -> Vec<u64> {
+        let offer_ids = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .user_incoming_offers(user)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        offer_ids.into_iter().collect()
+    }
+
+    pub async fn user_active_offers(&mut self, user: &Bech32Address) -> Vec<Offer<StaticApi>> {
+        let raw = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_user_active_offers(user)
+            .returns(ReturnsRawResult)
+            .run()
+            .await;
+
+        decode_offers(raw)
+    }
+
+    pub async fn user_incoming_active_offers(&mut self, user: &Bech32Address) -> Vec<Offer<StaticApi>> {
+        let raw = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_user_incoming_active_offers(user)
+            .returns(ReturnsRawResult)
+            .run()
+            .await;
+
+        decode_offers(raw)
+    }
+
+    pub async fn user_closed_offers(
+        &mut self,
+        user: &Bech32Address,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<Offer<StaticApi>> {
+        let raw = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_user_closed_offers(user, offset, limit)
+            .returns(ReturnsRawResult)
+            .run()
+            .await;
+
+        decode_offers(raw)
+    }
+
+    pub async fn user_incoming_closed_offers(
+        &mut self,
+        user: &Bech32Address,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<Offer<StaticApi>> {
+        let raw = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_user_incoming_closed_offers(user, offset, limit)
+            .returns(ReturnsRawResult)
+            .run()
+            .await;
+
+        decode_offers(raw)
+    }
+
+    pub async fn delivery_call(&mut self, offer_id: u64) {
+        let delivery_call = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_delivery_call(offer_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let in_progress = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .delivery_in_progress(offer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match delivery_call.into_option() {
+            Some(delivery_call) => println!(
+                "Endpoint {}, {} arguments, gas limit {}, in progress: {in_progress}",
+                String::from_utf8_lossy(&delivery_call.endpoint.to_boxed_bytes().into_vec()),
+                delivery_call.arguments.len(),
+                delivery_call.gas_limit
+            ),
+            None => println!("Offer {offer_id} has no delivery call"),
+        }
+    }
+
+    pub async fn release_condition(&mut self, offer_id: u64) {
+        let release_condition = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_release_condition(offer_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        match release_condition.into_option() {
+            Some(condition) => println!(
+                "Target {}, view {}, {} arguments, expected 0x{}",
+                Bech32Address::from(condition.target.to_address()),
+                String::from_utf8_lossy(&condition.view.to_boxed_bytes().into_vec()),
+                condition.arguments.len(),
+                multiversx_sc_snippets::hex::encode(condition.expected.to_boxed_bytes().into_vec())
+            ),
+            None => println!("Offer {offer_id} has no release condition"),
+        }
+    }
+
+    pub async fn usd_amount(&mut self, offer_id: u64) -> RustBigUint {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .usd_amount(offer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn price_oracle(&mut self) {
+        let oracle = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_price_oracle()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let max_price_age = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .max_price_age()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match oracle.into_option() {
+            Some(oracle) => println!(
+                "Price oracle {}, max price age {max_price_age}s",
+                Bech32Address::from(oracle.to_address())
+            ),
+            None => println!("Price oracle not set"),
+        }
+    }
+
+    pub async fn swap_routes(&mut self) {
+        let wrapper = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_egld_wrapper()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let pairs = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_swap_pairs()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        match wrapper.into_option() {
+            Some(wrapper) => println!("EGLD wrapper {}", Bech32Address::from(wrapper.to_address())),
+            None => println!("EGLD wrapper not set"),
+        }
+        for pair in pairs {
+            let (token_out, pair) = pair.into_tuple();
+            println!("{token_out}: {}", Bech32Address::from(pair.to_address()));
+        }
+    }
+
+    pub async fn token_restrictions(&mut self) {
+        let allowed_tokens = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .allowed_tokens()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let limits = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_token_limits()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!("allowed tokens:");
+        for token in allowed_tokens {
+            println!("  {token}");
+        }
+        println!("amount limits (max 0 = no limit):");
+        for limit in limits {
+            let (token, limits) = limit.into_tuple();
+            println!(
+                "  {}: {}..{}",
+                token.into_name(),
+                limits.min_amount.to_display(),
+                limits.max_amount.to_display()
+            );
+        }
+    }
+
+    pub async fn liquid_staking(&mut self) {
+        let liquid_staking = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_liquid_staking()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let recipient_yield_share = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .recipient_yield_share()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match liquid_staking.into_option() {
+            Some(liquid_staking) => println!(
+                "Liquid staking {}, recipient yield share {recipient_yield_share} bps",
+                Bech32Address::from(liquid_staking.to_address())
+            ),
+            None => println!("Liquid staking not set"),
+        }
+    }
+
+    pub async fn staking_position(&mut self, offer_id: u64) {
+        let position = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_staking_position(offer_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        match position.into_option() {
+            Some(position) => println!(
+                "{} {} from {}, recipient yield share {} bps",
+                position.ls_amount.to_display(),
+                position.ls_token,
+                Bech32Address::from(position.liquid_staking.to_address()),
+                position.recipient_yield_share
+            ),
+            None => println!("Offer {offer_id} has no staking position"),
+        }
+    }
+
+    pub async fn auction(&mut self, offer_id: u64) {
+        let auction = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_auction(offer_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let highest_bid = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .highest_bid(offer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match auction.into_option() {
+            Some(auction) => {
+                let kind = match auction.kind {
+                    empty_proxy::AuctionKind::English => "English",
+                    empty_proxy::AuctionKind::Dutch => "Dutch",
+                    empty_proxy::AuctionKind::Sealed => "Sealed-bid",
+                };
+                println!("{kind} auction of {} (nonce {})", auction.token.into_name(), auction.nonce);
+                println!(
+                    "  start price {}, end price {}, deadline {}",
+                    auction.start_price.to_display(),
+                    auction.end_price.to_display(),
+                    auction.deadline
+                );
+                if auction.kind == empty_proxy::AuctionKind::Sealed {
+                    println!(
+                        "  reveal deadline {}, unrevealed bid penalty {} bps",
+                        auction.reveal_deadline, auction.unrevealed_penalty
+                    );
+                }
+                println!("  highest bid {highest_bid}");
+            },
+            None => println!("Offer {offer_id} is not an auction"),
+        }
+    }
+
+    pub async fn sealed_bids(&mut self, offer_id: u64) {
+        let bids = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_sealed_bids(offer_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let penalty = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .unrevealed_bid_penalty()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        for bid in bids {
+            let (bidder, deposit) = bid.into_tuple();
+            println!("{}: deposit {}", Bech32Address::from(bidder.to_address()), deposit.to_display());
+        }
+        println!("Unrevealed bid penalty for new auctions: {penalty} bps");
+    }
+
+    pub async fn pending_refund(&mut self, address: &Bech32Address) -> RustBigUint {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .pending_refunds(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn dutch_auction_price(&mut self, offer_id: u64) -> RustBigUint {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_dutch_auction_price(offer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn sell_offer(&mut self, offer_id: u64) {
+        let sell_offer = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_sell_offer(offer_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let Some(sell_offer) = sell_offer.into_option() else {
+            println!("Offer {offer_id} is not a sell offer");
+            return;
+        };
+
+        let offer = self.offer(offer_id).await.unwrap();
+
+        println!("Sell offer of {} (nonce {})", sell_offer.token.into_name(), sell_offer.nonce);
+        println!(
+            "  price {} for {}, min fill {}, remaining {}",
+            sell_offer.price.to_display(),
+            offer.amount.to_display(),
+            sell_offer.min_fill.to_display(),
+            offer.remaining_amount.to_display()
+        );
+    }
+
+    pub async fn fill_price(&mut self, offer_id: u64, amount: u128) -> RustBigUint {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_fill_price(offer_id, BigUint::<StaticApi>::from(amount))
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn archived_offer(&mut self, offer_id: u64) {
+        let summary = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .archived_offer(offer_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!(
+            "Archived offer {}: status {}, amount {}, closed at {}",
+            summary.offer_id,
+            proxy_status_name(&summary.status),
+            summary.amount.to_display(),
+            summary.closed_timestamp
+        );
+    }
+
+    pub async fn archive_retention_period(&mut self) -> u64 {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .archive_retention_period()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn stats(&mut self) {
+        let stats = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_stats()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!(
+            "Created: {}, active: {}, completed: {}, cancelled: {}",
+            stats.total_created, stats.active_count, stats.completed_count, stats.cancelled_count
+        );
+        for token_stats in stats.tokens.iter() {
+            println!(
+                "{}: value locked {}, volume {}",
+                token_stats.token.clone().into_name(),
+                token_stats.value_locked.to_display(),
+                token_stats.volume.to_display()
+            );
+        }
+    }
+
+    pub async fn reputation(&mut self, user: &Bech32Address) {
+        let reputation = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_user_reputation(user)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!(
+            "Created: {}, completed as creator: {}, cancelled as creator: {}, accepted as recipient: {}, disputes lost: {}",
+            reputation.offers_created,
+            reputation.completed_as_creator,
+            reputation.cancelled_as_creator,
+            reputation.accepted_as_recipient,
+            reputation.disputes_lost
+        );
+    }
+
+    pub async fn blocked_addresses(&mut self) {
+        let addresses = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .blocked_addresses()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        for address in addresses {
+            println!("{}", Bech32Address::from(address.to_address()));
+        }
+    }
+
+    pub async fn is_address_blocked(&mut self, address: &Bech32Address) -> bool {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .is_address_blocked(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn role_holders(&mut self, role: empty_proxy::Role) {
+        let addresses = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .role_holders(role)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        for address in addresses {
+            println!("{}", Bech32Address::from(address.to_address()));
+        }
+    }
+
+    pub async fn has_role(&mut self, role: empty_proxy::Role, address: &Bech32Address) -> bool {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .has_role(role, address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn pending_admins(&mut self) {
+        let addresses = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .pending_admins()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        for address in addresses {
+            println!("{}", Bech32Address::from(address.to_address()));
+        }
+    }
+
+    pub async fn error_catalogue(&mut self) {
+        let catalogue = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_error_catalogue()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        for entry in catalogue {
+            let (_, message) = entry.into_tuple();
+            println!("{message}");
+        }
+    }
+
+    pub async fn migration_progress(&mut self) {
+        let (legacy_offer_count, legacy_offers_remaining) = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(EscrowContractProxy)
+            .get_migration_progress()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+            .into_tuple();
+
+        println!("Legacy offers: {legacy_offer_count}, remaining: {legacy_offers_remaining}");
+    }
+}
+
+fn bech32(address: &str) -> Bech32Address {
+    Bech32Address::from_bech32_string(address.to_owned())
+}
+
+fn hex_arg(arg: &str) -> Vec<u8> {
+    multiversx_sc_snippets::hex::decode(arg).expect("arguments must be hex encoded")
+}
+
+fn token_or_egld(token: Option<&str>) -> EgldOrEsdtTokenIdentifier<StaticApi> {
+    match token {
+        Some(token) => EgldOrEsdtTokenIdentifier::esdt(token),
+        None => EgldOrEsdtTokenIdentifier::egld(),
+    }
+}
+
+fn parse_auction_kind(kind: &str) -> empty_proxy::AuctionKind {
+    match kind {
+        "english" => empty_proxy::AuctionKind::English,
+        "dutch" => empty_proxy::AuctionKind::Dutch,
+        "sealed" => empty_proxy::AuctionKind::Sealed,
+        _ => panic!("unknown auction kind: {kind}"),
+    }
+}
+
+fn parse_role(role: &str) -> empty_proxy::Role {
+    match role {
+        "admin" => empty_proxy::Role::Admin,
+        "pauser" => empty_proxy::Role::Pauser,
+        "fee-manager" => empty_proxy::Role::FeeManager,
+        "arbiter" => empty_proxy::Role::Arbiter,
+        "compliance-officer" => empty_proxy::Role::ComplianceOfficer,
+        _ => panic!("unknown role: {role}"),
+    }
+}
+
+fn decode_offers(raw: ManagedVec<StaticApi, ManagedBuffer<StaticApi>>) -> Vec<Offer<StaticApi>> {
+    raw.iter()
+        .map(|buffer| Offer::top_decode(buffer.clone()).expect("invalid offer encoding"))
+        .collect()
+}
+
+fn status_name(status: &OfferStatus) -> &'static str {
+    match status {
+        OfferStatus::Active => "Active",
+        OfferStatus::Completed => "Completed",
+        OfferStatus::Cancelled => "Cancelled",
+        OfferStatus::PendingDelivery => "PendingDelivery",
+    }
+}
+
+fn proxy_status_name(status: &empty_proxy::OfferStatus) -> &'static str {
+    match status {
+        empty_proxy::OfferStatus::Active => "Active",
+        empty_proxy::OfferStatus::Completed => "Completed",
+        empty_proxy::OfferStatus::Cancelled => "Cancelled",
+        empty_proxy::OfferStatus::PendingDelivery => "PendingDelivery",
+    }
+}
+
+fn print_offer(offer: &Offer<StaticApi>) {
+    println!(
+        "Offer {}: {} -> {}, amount {}, status {}, created at {}",
+        offer.offer_id,
+        Bech32Address::from(offer.creator.to_address()),
+        Bech32Address::from(offer.recipient.to_address()),
+        offer.amount.to_display(),
+        status_name(&offer.status),
+        offer.created_timestamp
+    );
+    if offer.remaining_amount != offer.amount {
+        println!("  remaining {}", offer.remaining_amount.to_display());
+    }
+}
+
+fn print_offers(offers: &[Offer<StaticApi>]) {
+    if offers.is_empty() {
+        println!("No offers");
+    }
+    for offer in offers {
+        print_offer(offer);
+    }
+}
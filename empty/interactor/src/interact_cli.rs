@@ -0,0 +1,417 @@
+use clap::{Args, Parser, Subcommand};
+
+// Escrow kontratı için komut satırı arayüzü
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+// Desteklenen komutlar - endpoint'ler ve tüm view fonksiyonları
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(name = "deploy", about = "Deploy the contract")]
+    Deploy,
+    #[command(name = "upgrade", about = "Upgrade the deployed contract")]
+    Upgrade,
+    #[command(name = "create", about = "Create an offer by locking EGLD")]
+    Create(CreateArgs),
+    #[command(name = "accept", about = "Accept an offer as its recipient")]
+    Accept(AcceptArgs),
+    #[command(name = "cancel", about = "Cancel an offer as its creator")]
+    Cancel(OfferIdArgs),
+    #[command(name = "release", about = "Release an offer to its recipient as its creator")]
+    Release(OfferIdArgs),
+    #[command(name = "set-delivery-call", about = "Deliver an offer to a contract recipient through an endpoint call")]
+    SetDeliveryCall(DeliveryCallArgs),
+    #[command(name = "retry-delivery", about = "Retry a failed delivery")]
+    RetryDelivery(OfferIdArgs),
+    #[command(name = "try-release", about = "Release an offer whose release condition is met")]
+    TryRelease(OfferIdArgs),
+    #[command(name = "create-auction", about = "Auction EGLD or a token (e.g. an NFT) for EGLD bids")]
+    CreateAuction(CreateAuctionArgs),
+    #[command(name = "bid", about = "Bid on an English auction")]
+    Bid(PayOfferArgs),
+    #[command(name = "buy", about = "Buy a Dutch auction at its current price")]
+    Buy(PayOfferArgs),
+    #[command(name = "commit-bid", about = "Commit a sealed bid with a deposit")]
+    CommitBid(CommitBidArgs),
+    #[command(name = "reveal-bid", about = "Reveal a sealed bid")]
+    RevealBid(RevealBidArgs),
+    #[command(name = "settle-auction", about = "Settle an English or sealed-bid auction after it ends")]
+    SettleAuction(OfferIdArgs),
+    #[command(name = "withdraw-refund", about = "Withdraw the EGLD refunded to the wallet by auctions")]
+    WithdrawRefund,
+    #[command(name = "set-unrevealed-bid-penalty", about = "Set the share of unrevealed sealed-bid deposits paid to the seller")]
+    SetUnrevealedBidPenalty(PenaltyArgs),
+    #[command(name = "create-sell-offer", about = "Lock tokens for sale to anyone in partial fills, priced in EGLD")]
+    CreateSellOffer(CreateSellOfferArgs),
+    #[command(name = "accept-partial", about = "Buy part of a sell offer")]
+    AcceptPartial(AcceptPartialArgs),
+    #[command(name = "set-egld-wrapper", about = "Set the contract that wraps EGLD before a payout swap")]
+    SetEgldWrapper(AddressArgs),
+    #[command(name = "set-swap-pair", about = "Set the pair contract used to swap payouts into a token")]
+    SetSwapPair(SwapPairArgs),
+    #[command(name = "remove-swap-pair", about = "Remove the swap pair of a token")]
+    RemoveSwapPair(TokenArgs),
+    #[command(name = "allow-token", about = "Allow an ESDT as payment for offers, auctions and sell offers")]
+    AllowToken(TokenArgs),
+    #[command(name = "remove-allowed-token", about = "Remove an ESDT from the allowed tokens")]
+    RemoveAllowedToken(TokenArgs),
+    #[command(name = "set-token-limits", about = "Set the minimum and maximum offer amount of a token")]
+    SetTokenLimits(TokenLimitsArgs),
+    #[command(name = "remove-token-limits", about = "Remove the amount limits of a token")]
+    RemoveTokenLimits(OptionalTokenArgs),
+    #[command(name = "set-price-oracle", about = "Set the price oracle used by USD-denominated offers")]
+    SetPriceOracle(PriceOracleArgs),
+    #[command(name = "set-liquid-staking", about = "Set the liquid staking contract and the recipient's yield share")]
+    SetLiquidStaking(LiquidStakingArgs),
+    #[command(name = "last-offer-id", about = "Last created offer id")]
+    LastOfferId,
+    #[command(name = "offer", about = "Offer details")]
+    Offer(OfferIdArgs),
+    #[command(name = "offer-by-reference", about = "Offer by creator and external reference")]
+    OfferByReference(ReferenceArgs),
+    #[command(name = "active-offers", about = "All active offers")]
+    ActiveOffers,
+    #[command(name = "user-offers", about = "Ids of the offers created by an address")]
+    UserOffers(AddressArgs),
+    #[command(name = "user-incoming-offers", about = "Ids of the offers addressed to an address")]
+    UserIncomingOffers(AddressArgs),
+    #[command(name = "user-active-offers", about = "Active offers created by an address")]
+    UserActiveOffers(AddressArgs),
+    #[command(name = "user-incoming-active-offers", about = "Active offers addressed to an address")]
+    UserIncomingActiveOffers(AddressArgs),
+    #[command(name = "user-closed-offers", about = "Closed offers created by an address, newest first")]
+    UserClosedOffers(PageArgs),
+    #[command(name = "user-incoming-closed-offers", about = "Closed offers addressed to an address, newest first")]
+    UserIncomingClosedOffers(PageArgs),
+    #[command(name = "delivery-call", about = "Delivery call of an offer")]
+    DeliveryCall(OfferIdArgs),
+    #[command(name = "release-condition", about = "Release condition of an offer")]
+    ReleaseCondition(OfferIdArgs),
+    #[command(name = "usd-amount", about = "USD amount owed by a USD-denominated offer")]
+    UsdAmount(OfferIdArgs),
+    #[command(name = "price-oracle", about = "Price oracle used by USD-denominated offers")]
+    PriceOracle,
+    #[command(name = "swap-routes", about = "EGLD wrapper and swap pairs used for payout swaps")]
+    SwapRoutes,
+    #[command(name = "token-restrictions", about = "Allowed tokens and per-token amount limits")]
+    TokenRestrictions,
+    #[command(name = "liquid-staking", about = "Liquid staking contract and the recipient's yield share")]
+    LiquidStaking,
+    #[command(name = "staking-position", about = "Liquid staking position of an offer")]
+    StakingPosition(OfferIdArgs),
+    #[command(name = "auction", about = "Auction settings and highest bid of an offer")]
+    Auction(OfferIdArgs),
+    #[command(name = "sealed-bids", about = "Unrevealed sealed bids of an auction and the penalty for new auctions")]
+    SealedBids(OfferIdArgs),
+    #[command(name = "pending-refund", about = "EGLD refunded to an address by auctions and not withdrawn yet")]
+    PendingRefund(AddressArgs),
+    #[command(name = "dutch-auction-price", about = "Current price of a Dutch auction")]
+    DutchAuctionPrice(OfferIdArgs),
+    #[command(name = "sell-offer", about = "Sell offer settings and remaining amount")]
+    SellOffer(OfferIdArgs),
+    #[command(name = "fill-price", about = "EGLD price of buying an amount from a sell offer")]
+    FillPrice(FillArgs),
+    #[command(name = "archived-offer", about = "Summary of an archived offer")]
+    ArchivedOffer(OfferIdArgs),
+    #[command(name = "archive-retention-period", about = "Seconds before a closed offer can be archived")]
+    ArchiveRetentionPeriod,
+    #[command(name = "stats", about = "Aggregate offer statistics")]
+    Stats,
+    #[command(name = "reputation", about = "Trade history counters of an address")]
+    Reputation(AddressArgs),
+    #[command(name = "blocked-addresses", about = "Blocked addresses")]
+    BlockedAddresses,
+    #[command(name = "is-blocked", about = "Whether an address is blocked")]
+    IsBlocked(AddressArgs),
+    #[command(name = "role-holders", about = "Addresses holding a role")]
+    RoleHolders(RoleArgs),
+    #[command(name = "has-role", about = "Whether an address holds a role")]
+    HasRole(RoleAddressArgs),
+    #[command(name = "pending-admins", about = "Addresses proposed for the Admin role")]
+    PendingAdmins,
+    #[command(name = "error-catalogue", about = "Error codes and messages")]
+    ErrorCatalogue,
+    #[command(name = "migration-progress", about = "Legacy offer migration progress")]
+    MigrationProgress,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct CreateArgs {
+    /// Recipient address (bech32)
+    #[arg(long = "buyer")]
+    pub buyer: String,
+    /// Amount to lock, in the smallest EGLD denomination
+    #[arg(long = "amount")]
+    pub amount: u128,
+    #[arg(long = "memo")]
+    pub memo: Option<String>,
+    #[arg(long = "reference")]
+    pub reference: Option<String>,
+    #[arg(long = "terms-hash")]
+    pub terms_hash: Option<String>,
+    #[arg(long = "min-recipient-trades")]
+    pub min_recipient_trades: Option<u64>,
+    /// Contract whose view must return the expected value before try-release (bech32)
+    #[arg(long = "condition-target", requires = "condition_view")]
+    pub condition_target: Option<String>,
+    #[arg(long = "condition-view", requires = "condition_target")]
+    pub condition_view: Option<String>,
+    /// View arguments, hex encoded
+    #[arg(long = "condition-arg", requires = "condition_target")]
+    pub condition_args: Vec<String>,
+    /// Expected view result, hex encoded
+    #[arg(long = "condition-expected", requires = "condition_target", default_value = "")]
+    pub condition_expected: String,
+    /// USD amount owed to the recipient, in cents; paid in EGLD at the oracle price on release
+    #[arg(long = "usd-amount")]
+    pub usd_amount: Option<u64>,
+    /// Delegate the locked EGLD to the configured liquid staking contract
+    #[arg(long = "stake")]
+    pub stake: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct CreateAuctionArgs {
+    /// Auction kind: english, dutch or sealed
+    #[arg(long = "kind")]
+    pub kind: String,
+    /// English: minimum bid, Dutch: starting price (EGLD, in atomic units)
+    #[arg(long = "start-price")]
+    pub start_price: u128,
+    /// Dutch: price at the deadline (EGLD, in atomic units); must be 0 for English auctions
+    #[arg(long = "end-price", default_value_t = 0)]
+    pub end_price: u128,
+    /// Auction duration in seconds; bidding window for sealed-bid auctions
+    #[arg(long = "duration")]
+    pub duration: u64,
+    /// Sealed-bid auctions: reveal window in seconds, after the bidding window
+    #[arg(long = "reveal-duration")]
+    pub reveal_duration: Option<u64>,
+    /// Token to auction; EGLD if left out
+    #[arg(long = "token")]
+    pub token: Option<String>,
+    /// Token nonce, for NFTs and SFTs
+    #[arg(long = "nonce", default_value_t = 0)]
+    pub nonce: u64,
+    /// Amount to auction, in atomic units
+    #[arg(long = "amount")]
+    pub amount: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct CreateSellOfferArgs {
+    /// Token to sell; EGLD if left out
+    #[arg(long = "token")]
+    pub token: Option<String>,
+    /// Token nonce, for SFTs
+    #[arg(long = "nonce", default_value_t = 0)]
+    pub nonce: u64,
+    /// Amount to sell, in atomic units
+    #[arg(long = "amount")]
+    pub amount: u128,
+    /// EGLD price of the whole amount, in atomic units; fills pay a proportional share
+    #[arg(long = "price")]
+    pub price: u128,
+    /// Smallest amount a single fill may buy, unless it takes the whole remainder
+    #[arg(long = "min-fill")]
+    pub min_fill: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct FillArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+    /// Token amount to buy, in atomic units
+    #[arg(long = "amount")]
+    pub amount: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AcceptPartialArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+    /// Token amount to buy, in atomic units
+    #[arg(long = "amount")]
+    pub amount: u128,
+    /// EGLD to pay, in atomic units; the fill price is queried if left out and any excess is refunded
+    #[arg(long = "payment")]
+    pub payment: Option<u128>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PayOfferArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+    /// EGLD amount to pay, in atomic units
+    #[arg(long = "amount")]
+    pub amount: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct CommitBidArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+    /// Bid amount, kept secret until the reveal (EGLD, in atomic units)
+    #[arg(long = "amount")]
+    pub amount: u128,
+    /// Secret salt; keep it to reveal the bid
+    #[arg(long = "salt")]
+    pub salt: String,
+    /// EGLD deposit sent with the commitment; must cover the bid
+    #[arg(long = "deposit")]
+    pub deposit: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RevealBidArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+    #[arg(long = "amount")]
+    pub amount: u128,
+    #[arg(long = "salt")]
+    pub salt: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PenaltyArgs {
+    /// Penalty in basis points
+    #[arg(long = "penalty")]
+    pub penalty: u32,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct OfferIdArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AcceptArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+    /// Token to receive instead of EGLD, swapped through the configured pair
+    #[arg(long = "swap-token")]
+    pub swap_token: Option<String>,
+    /// Minimum amount of the swap token to receive
+    #[arg(long = "min-amount-out", requires = "swap_token")]
+    pub min_amount_out: Option<u128>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct LiquidStakingArgs {
+    /// Liquid staking contract address (bech32)
+    #[arg(long = "address")]
+    pub address: String,
+    /// Share of the accrued yield paid to the recipient, in basis points
+    #[arg(long = "recipient-yield-share")]
+    pub recipient_yield_share: u32,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SwapPairArgs {
+    /// Token the payout is swapped into
+    #[arg(long = "token-out")]
+    pub token_out: String,
+    /// Pair contract address (bech32)
+    #[arg(long = "pair")]
+    pub pair: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TokenArgs {
+    #[arg(long = "token")]
+    pub token: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct OptionalTokenArgs {
+    /// Token identifier; EGLD if left out
+    #[arg(long = "token")]
+    pub token: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TokenLimitsArgs {
+    /// Token identifier; EGLD if left out
+    #[arg(long = "token")]
+    pub token: Option<String>,
+    /// Smallest amount accepted, in atomic units
+    #[arg(long = "min-amount")]
+    pub min_amount: u128,
+    /// Largest amount accepted, in atomic units; 0 for no limit
+    #[arg(long = "max-amount", default_value_t = 0)]
+    pub max_amount: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct DeliveryCallArgs {
+    #[arg(long = "offer-id")]
+    pub offer_id: u64,
+    /// Gas reserved for the call on the recipient contract
+    #[arg(long = "gas-limit")]
+    pub gas_limit: u64,
+    #[arg(long = "endpoint")]
+    pub endpoint: String,
+    /// Endpoint arguments, hex encoded
+    #[arg(long = "arg")]
+    pub args: Vec<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PriceOracleArgs {
+    /// Price aggregator address (bech32)
+    #[arg(long = "oracle")]
+    pub oracle: String,
+    /// Maximum accepted age of the oracle price, in seconds
+    #[arg(long = "max-price-age")]
+    pub max_price_age: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AddressArgs {
+    /// Address (bech32)
+    #[arg(long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PageArgs {
+    /// Address (bech32)
+    #[arg(long = "address")]
+    pub address: String,
+    #[arg(long = "offset", default_value = "0")]
+    pub offset: usize,
+    #[arg(long = "limit", default_value = "10")]
+    pub limit: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ReferenceArgs {
+    /// Creator address (bech32)
+    #[arg(long = "creator")]
+    pub creator: String,
+    #[arg(long = "reference")]
+    pub reference: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RoleArgs {
+    /// admin, pauser, fee-manager, arbiter or compliance-officer
+    #[arg(long = "role")]
+    pub role: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RoleAddressArgs {
+    /// admin, pauser, fee-manager, arbiter or compliance-officer
+    #[arg(long = "role")]
+    pub role: String,
+    /// Address (bech32)
+    #[arg(long = "address")]
+    pub address: String,
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+use std::io::Read;
+
+// Ayar dosyasının varsayılan yolu, interactor dizinine göredir
+const CONFIG_FILE: &str = "config.toml";
+
+// Bağlanılan ağın türü
+// simulator: yerel chain simulator, test cüzdanları otomatik olarak fonlanır
+// real: gerçek bir gateway (devnet/testnet/mainnet), PEM dosyası zorunludur
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainType {
+    Real,
+    Simulator,
+}
+
+// Interactor ayarları - config.toml dosyasından okunur
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub gateway_uri: String,
+    pub chain_type: ChainType,
+    // İşlemleri imzalayan cüzdanın PEM dosyası
+    // Verilmezse (sadece simulator'da) test cüzdanı alice kullanılır
+    pub pem_path: Option<String>,
+}
+
+impl Config {
+    // Ayarları config.toml dosyasından okur
+    pub fn load_config() -> Self {
+        let mut file = std::fs::File::open(CONFIG_FILE).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        toml::from_str(&content).unwrap()
+    }
+
+    // Yerel chain simulator için ayarlar (testlerde kullanılır)
+    pub fn chain_simulator_config() -> Self {
+        Config {
+            gateway_uri: "http://localhost:8085".to_owned(),
+            chain_type: ChainType::Simulator,
+            pem_path: None,
+        }
+    }
+
+    pub fn gateway_uri(&self) -> &str {
+        &self.gateway_uri
+    }
+
+    pub fn use_chain_simulator(&self) -> bool {
+        self.chain_type == ChainType::Simulator
+    }
+}
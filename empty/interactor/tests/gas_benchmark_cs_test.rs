@@ -0,0 +1,503 @@
+use empty::empty_proxy::{AuctionKind, EscrowContractProxy, ReleaseCondition, Role};
+use empty_interact::{Config, ContractInteract};
+use multiversx_sc_snippets::imports::*;
+use std::collections::BTreeMap;
+
+// Gaz ölçüm testi - kontratı N teklifle doldurup her endpoint ve view'ın harcadığı gazı ölçer
+// Rust VM gaz ölçmediği için ölçümler chain simulator üzerinde yapılır
+// Çalıştırmak için:
+// - simulator'ı başlatın: sc-meta cs start
+// - kontratı ve mock kontratları derleyin: sc-meta all build
+// - cargo test --features chain-simulator-tests --test gas_benchmark_cs_test -- --nocapture
+//
+// Sonuçlar gas_report.toml dosyasına yazılır ve gas_baseline.toml ile karşılaştırılır
+// Baseline dosyası yoksa test başarısız olur, UPDATE_GAS_BASELINE=1 verildiğinde rapor baseline olarak kaydedilir
+
+const OFFER_COUNTS: [usize; 3] = [10, 100, 1000];
+const REPORT_FILE: &str = "gas_report.toml";
+const BASELINE_FILE: &str = "gas_baseline.toml";
+const UPDATE_BASELINE_ENV: &str = "UPDATE_GAS_BASELINE";
+// Baseline'a göre izin verilen artış yüzdesi
+const TOLERANCE_PERCENT: u64 = 5;
+// View'lar da işlem olarak çağrılır, büyük listeler için yüksek gaz limiti gerekir
+const BENCH_GAS_LIMIT: u64 = 600_000_000;
+const BENCH_REFERENCE: &str = "bench";
+// Açık artırmalarda teklif verme ve açıklama süreleri (saniye)
+const BENCH_AUCTION_DURATION: u64 = 60;
+const BENCH_REVEAL_DURATION: u64 = 600;
+// Chain simulator her blokta zamanı 6 saniye ilerletir, süreyi geçmek için üretilecek blok sayısı
+const BLOCKS_PAST_AUCTION: u64 = BENCH_AUCTION_DURATION / 6 + 1;
+const BENCH_ORDER_ID: u8 = 7;
+const BENCH_SWAP_TOKEN: &str = "USDC-123456";
+
+const RECIPIENT_MOCK_PATH: MxscPath = MxscPath::new("../mocks/recipient-mock/output/recipient-mock.mxsc.json");
+const DAO_MOCK_PATH: MxscPath = MxscPath::new("../mocks/dao-mock/output/dao-mock.mxsc.json");
+const PRICE_AGGREGATOR_MOCK_PATH: MxscPath =
+    MxscPath::new("../mocks/price-aggregator-mock/output/price-aggregator-mock.mxsc.json");
+const EGLD_WRAPPER_MOCK_PATH: MxscPath = MxscPath::new("../mocks/egld-wrapper-mock/output/egld-wrapper-mock.mxsc.json");
+const PAIR_MOCK_PATH: MxscPath = MxscPath::new("../mocks/pair-mock/output/pair-mock.mxsc.json");
+const LIQUID_STAKING_MOCK_PATH: MxscPath =
+    MxscPath::new("../mocks/liquid-staking-mock/output/liquid-staking-mock.mxsc.json");
+
+// Teklif sayısı -> (fonksiyon adı -> harcanan gaz)
+type GasReport = BTreeMap<String, BTreeMap<String, u64>>;
+
+// Bir endpoint veya view'ı işlem olarak gönderir ve harcanan gazı döndürür
+macro_rules! gas_of {
+    ($interact:expr, $from:expr, $call:ident($($arg:expr),*)) => {{
+        let contract = $interact.contract_address().clone();
+        $interact
+            .interactor
+            .tx()
+            .from($from)
+            .to(&contract)
+            .gas(BENCH_GAS_LIMIT)
+            .typed(EscrowContractProxy)
+            .$call($($arg),*)
+            .returns(ReturnsGasUsed)
+            .run()
+            .await
+    }};
+    ($interact:expr, $from:expr, $call:ident($($arg:expr),*), egld $amount:expr) => {{
+        let contract = $interact.contract_address().clone();
+        $interact
+            .interactor
+            .tx()
+            .from($from)
+            .to(&contract)
+            .gas(BENCH_GAS_LIMIT)
+            .typed(EscrowContractProxy)
+            .$call($($arg),*)
+            .egld($amount)
+            .returns(ReturnsGasUsed)
+            .run()
+            .await
+    }};
+}
+
+// Mock kontratı cüzdandan deploy eder ve adresini döndürür
+async fn deploy_mock(interact: &mut ContractInteract, code_path: MxscPath<'static>) -> Bech32Address {
+    let owner = interact.wallet_address.clone();
+    interact
+        .interactor
+        .tx()
+        .from(&owner)
+        .gas(30_000_000u64)
+        .raw_deploy()
+        .code(code_path)
+        .code_metadata(CodeMetadata::UPGRADEABLE)
+        .returns(ReturnsNewBech32Address)
+        .run()
+        .await
+}
+
+// Mock kontratın ayar endpoint'ini çağırır
+async fn call_mock(interact: &mut ContractInteract, mock: &Bech32Address, endpoint: &str, arguments: Vec<Vec<u8>>) {
+    let owner = interact.wallet_address.clone();
+    interact
+        .interactor
+        .tx()
+        .from(&owner)
+        .to(mock)
+        .gas(30_000_000u64)
+        .raw_call(endpoint)
+        .arguments_raw(ManagedArgBuffer::from(arguments))
+        .run()
+        .await;
+}
+
+// Kontratı yeniden deploy eder, offer_count kadar teklif oluşturur ve tüm fonksiyonları ölçer
+async fn measure(interact: &mut ContractInteract, offer_count: usize) -> BTreeMap<String, u64> {
+    let owner = interact.wallet_address.clone();
+    let buyer = interact.register_wallet(test_wallets::bob()).await;
+    let other = interact.register_wallet(test_wallets::carol()).await;
+    let buyer_bech32: Bech32Address = buyer.clone().into();
+    let other_bech32: Bech32Address = other.clone().into();
+
+    interact.deploy().await;
+
+    // 1: kabul edilecek, 2: iptal edilecek, 3: engellenen adrese, 4: referanslı, 5: serbest bırakılacak
+    // geri kalanı aktif kalır
+    // Mock kontrat gerektiren endpoint'ler, açık artırmalar ve kısmi satışlar bu tekliflerden sonra ölçülür
+    interact.create(&buyer_bech32, 1, None, None, None, None, None, None, false).await;
+    interact.create(&buyer_bech32, 1, None, None, None, None, None, None, false).await;
+    interact.create(&other_bech32, 1, None, None, None, None, None, None, false).await;
+    interact
+        .create(&buyer_bech32, 1, None, Some(BENCH_REFERENCE), None, None, None, None, false)
+        .await;
+    for _ in 4..offer_count {
+        interact.create(&buyer_bech32, 1, None, None, None, None, None, None, false).await;
+    }
+
+    let mut gas = BTreeMap::new();
+
+    // Endpoint'ler
+    let contract = interact.contract_address().clone();
+    let create_gas = interact
+        .interactor
+        .tx()
+        .from(&owner)
+        .to(&contract)
+        .gas(BENCH_GAS_LIMIT)
+        .typed(EscrowContractProxy)
+        .create(
+            &buyer,
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<u64>::None,
+            OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<bool>::None,
+        )
+        .egld(1u64)
+        .returns(ReturnsGasUsed)
+        .run()
+        .await;
+    gas.insert("create".to_owned(), create_gas);
+    gas.insert(
+        "acceptOffer".to_owned(),
+        gas_of!(
+            interact,
+            &buyer,
+            accept_offer(1u64, OptionalValue::<MultiValue2<TokenIdentifier<StaticApi>, BigUint<StaticApi>>>::None)
+        ),
+    );
+    gas.insert("cancelOffer".to_owned(), gas_of!(interact, &owner, cancel_offer(2u64)));
+    gas.insert("releaseOffer".to_owned(), gas_of!(interact, &owner, release_offer(5u64)));
+    gas.insert(
+        "setArchiveRetentionPeriod".to_owned(),
+        gas_of!(interact, &owner, set_archive_retention_period(0u64)),
+    );
+    gas.insert(
+        "archiveOffers".to_owned(),
+        gas_of!(interact, &owner, archive_offers(MultiValueEncoded::from_iter([1u64]))),
+    );
+    gas.insert("blockAddress".to_owned(), gas_of!(interact, &owner, block_address(&other)));
+    gas.insert("adminRefundOffer".to_owned(), gas_of!(interact, &owner, admin_refund_offer(3u64)));
+    gas.insert("unblockAddress".to_owned(), gas_of!(interact, &owner, unblock_address(&other)));
+    gas.insert("grantRole".to_owned(), gas_of!(interact, &owner, grant_role(Role::Admin, &other)));
+    gas.insert("acceptAdminRole".to_owned(), gas_of!(interact, &other, accept_admin_role()));
+    gas.insert("revokeRole".to_owned(), gas_of!(interact, &owner, revoke_role(Role::Admin, &other)));
+    gas.insert("migrateOffers".to_owned(), gas_of!(interact, &owner, migrate_offers(1u64, 100u64)));
+    gas.insert(
+        "setUnrevealedBidPenalty".to_owned(),
+        gas_of!(interact, &owner, set_unrevealed_bid_penalty(1_000u32)),
+    );
+    // Ölçülen tüm teklifler sınır içinde kalır
+    gas.insert(
+        "allowToken".to_owned(),
+        gas_of!(interact, &owner, allow_token(TokenIdentifier::from(BENCH_SWAP_TOKEN))),
+    );
+    gas.insert(
+        "setTokenLimits".to_owned(),
+        gas_of!(interact, &owner, set_token_limits(EgldOrEsdtTokenIdentifier::egld(), 1u64, 0u64)),
+    );
+    measure_integrations(interact, &mut gas, &buyer).await;
+    measure_auctions(interact, &mut gas, &buyer, &other).await;
+    measure_sell_offer(interact, &mut gas, &buyer).await;
+
+    // View'lar
+    gas.insert("getLastOfferId".to_owned(), gas_of!(interact, &owner, last_offer_id()));
+    gas.insert("getOffer".to_owned(), gas_of!(interact, &owner, get_offer(4u64)));
+    gas.insert(
+        "getOfferByReference".to_owned(),
+        gas_of!(interact, &owner, get_offer_by_reference(&owner, BENCH_REFERENCE)),
+    );
+    gas.insert("getActiveOffers".to_owned(), gas_of!(interact, &owner, get_active_offers()));
+    gas.insert("getUserOffers".to_owned(), gas_of!(interact, &owner, user_offers(&owner)));
+    gas.insert(
+        "getUserIncomingOffers".to_owned(),
+        gas_of!(interact, &owner, user_incoming_offers(&buyer)),
+    );
+    gas.insert(
+        "getUserActiveOffers".to_owned(),
+        gas_of!(interact, &owner, get_user_active_offers(&owner)),
+    );
+    gas.insert(
+        "getUserIncomingActiveOffers".to_owned(),
+        gas_of!(interact, &owner, get_user_incoming_active_offers(&buyer)),
+    );
+    gas.insert(
+        "getUserClosedOffers".to_owned(),
+        gas_of!(interact, &owner, get_user_closed_offers(&owner, 0u32, 10u32)),
+    );
+    gas.insert(
+        "getUserIncomingClosedOffers".to_owned(),
+        gas_of!(interact, &owner, get_user_incoming_closed_offers(&buyer, 0u32, 10u32)),
+    );
+    gas.insert("getDeliveryCall".to_owned(), gas_of!(interact, &owner, get_delivery_call(4u64)));
+    gas.insert(
+        "isDeliveryInProgress".to_owned(),
+        gas_of!(interact, &owner, delivery_in_progress(4u64)),
+    );
+    gas.insert(
+        "getReleaseCondition".to_owned(),
+        gas_of!(interact, &owner, get_release_condition(4u64)),
+    );
+    gas.insert("getUsdAmount".to_owned(), gas_of!(interact, &owner, usd_amount(4u64)));
+    gas.insert("getPriceOracle".to_owned(), gas_of!(interact, &owner, get_price_oracle()));
+    gas.insert("getMaxPriceAge".to_owned(), gas_of!(interact, &owner, max_price_age()));
+    gas.insert("getEgldWrapper".to_owned(), gas_of!(interact, &owner, get_egld_wrapper()));
+    gas.insert("getSwapPairs".to_owned(), gas_of!(interact, &owner, get_swap_pairs()));
+    gas.insert("getAllowedTokens".to_owned(), gas_of!(interact, &owner, allowed_tokens()));
+    gas.insert("getTokenLimits".to_owned(), gas_of!(interact, &owner, get_token_limits()));
+    gas.insert("getLiquidStaking".to_owned(), gas_of!(interact, &owner, get_liquid_staking()));
+    gas.insert("getRecipientYieldShare".to_owned(), gas_of!(interact, &owner, recipient_yield_share()));
+    gas.insert("getStakingPosition".to_owned(), gas_of!(interact, &owner, get_staking_position(1u64)));
+    gas.insert("getAuction".to_owned(), gas_of!(interact, &owner, get_auction(1u64)));
+    gas.insert("getHighestBid".to_owned(), gas_of!(interact, &owner, highest_bid(1u64)));
+    gas.insert("getSealedBids".to_owned(), gas_of!(interact, &owner, get_sealed_bids(1u64)));
+    gas.insert("getUnrevealedBidPenalty".to_owned(), gas_of!(interact, &owner, unrevealed_bid_penalty()));
+    gas.insert("getSellOffer".to_owned(), gas_of!(interact, &owner, get_sell_offer(1u64)));
+    gas.insert("getArchivedOffer".to_owned(), gas_of!(interact, &owner, archived_offer(1u64)));
+    gas.insert(
+        "getArchiveRetentionPeriod".to_owned(),
+        gas_of!(interact, &owner, archive_retention_period()),
+    );
+    gas.insert("getStats".to_owned(), gas_of!(interact, &owner, get_stats()));
+    gas.insert("getUserReputation".to_owned(), gas_of!(interact, &owner, get_user_reputation(&owner)));
+    gas.insert("getBlockedAddresses".to_owned(), gas_of!(interact, &owner, blocked_addresses()));
+    gas.insert("isAddressBlocked".to_owned(), gas_of!(interact, &owner, is_address_blocked(&other)));
+    gas.insert("getRoleHolders".to_owned(), gas_of!(interact, &owner, role_holders(Role::Admin)));
+    gas.insert("hasRole".to_owned(), gas_of!(interact, &owner, has_role(Role::Admin, &other)));
+    gas.insert("getPendingAdmins".to_owned(), gas_of!(interact, &owner, pending_admins()));
+    gas.insert("getErrorCatalogue".to_owned(), gas_of!(interact, &owner, get_error_catalogue()));
+    gas.insert("getMigrationProgress".to_owned(), gas_of!(interact, &owner, get_migration_progress()));
+    gas.insert(
+        "removeAllowedToken".to_owned(),
+        gas_of!(interact, &owner, remove_allowed_token(TokenIdentifier::from(BENCH_SWAP_TOKEN))),
+    );
+    gas.insert(
+        "removeTokenLimits".to_owned(),
+        gas_of!(interact, &owner, remove_token_limits(EgldOrEsdtTokenIdentifier::egld())),
+    );
+
+    gas
+}
+
+// Mock kontrat gerektiren endpoint'ler: teslimat, koşullu serbest bırakma, oracle, takas ve likit staking ayarları
+async fn measure_integrations(interact: &mut ContractInteract, gas: &mut BTreeMap<String, u64>, buyer: &Address) {
+    let owner = interact.wallet_address.clone();
+    let buyer_bech32: Bech32Address = buyer.clone().into();
+
+    // Alıcı kontrat teslimatı reddeder, böylece teklif PendingDelivery durumunda kalır ve tekrar denenebilir
+    let marketplace = deploy_mock(interact, RECIPIENT_MOCK_PATH).await;
+    call_mock(interact, &marketplace, "setRejecting", vec![vec![1]]).await;
+    interact.create(&marketplace, 1, None, None, None, None, None, None, false).await;
+    let delivery_offer = interact.last_offer_id().await;
+    gas.insert(
+        "setDeliveryCall".to_owned(),
+        gas_of!(
+            interact,
+            &owner,
+            set_delivery_call(
+                delivery_offer,
+                10_000_000u64,
+                "onEscrowDelivery",
+                MultiValueVec::from(vec![vec![BENCH_ORDER_ID]])
+            )
+        ),
+    );
+    interact.release_offer(delivery_offer).await;
+    gas.insert("retryDelivery".to_owned(), gas_of!(interact, &owner, retry_delivery(delivery_offer)));
+
+    let dao = deploy_mock(interact, DAO_MOCK_PATH).await;
+    call_mock(interact, &dao, "setProposalPassed", vec![vec![BENCH_ORDER_ID], vec![1]]).await;
+    let condition = ReleaseCondition {
+        target: dao.to_address().into(),
+        view: ManagedBuffer::from("isProposalPassed"),
+        arguments: ManagedVec::from_single_item(ManagedBuffer::from(&[BENCH_ORDER_ID][..])),
+        expected: ManagedBuffer::from(&[1u8][..]),
+    };
+    interact
+        .create(&buyer_bech32, 1, None, None, None, None, Some(condition), None, false)
+        .await;
+    let conditional_offer = interact.last_offer_id().await;
+    gas.insert("tryRelease".to_owned(), gas_of!(interact, &owner, try_release(conditional_offer)));
+
+    let oracle = deploy_mock(interact, PRICE_AGGREGATOR_MOCK_PATH).await;
+    gas.insert("setPriceOracle".to_owned(), gas_of!(interact, &owner, set_price_oracle(&oracle, 300u64)));
+
+    let wrapper = deploy_mock(interact, EGLD_WRAPPER_MOCK_PATH).await;
+    let pair = deploy_mock(interact, PAIR_MOCK_PATH).await;
+    gas.insert("setEgldWrapper".to_owned(), gas_of!(interact, &owner, set_egld_wrapper(&wrapper)));
+    gas.insert(
+        "setSwapPair".to_owned(),
+        gas_of!(interact, &owner, set_swap_pair(TokenIdentifier::from(BENCH_SWAP_TOKEN), &pair)),
+    );
+    gas.insert("removeSwapPair".to_owned(), gas_of!(interact, &owner, remove_swap_pair(TokenIdentifier::from(BENCH_SWAP_TOKEN))));
+
+    let liquid_staking = deploy_mock(interact, LIQUID_STAKING_MOCK_PATH).await;
+    gas.insert(
+        "setLiquidStaking".to_owned(),
+        gas_of!(interact, &owner, set_liquid_staking(&liquid_staking, 5_000u32)),
+    );
+}
+
+// English, Dutch ve kapalı teklifli açık artırma endpoint'leri ve view'ları
+// Süreleri geçmek için chain simulator'da blok üretilir
+async fn measure_auctions(
+    interact: &mut ContractInteract,
+    gas: &mut BTreeMap<String, u64>,
+    buyer: &Address,
+    other: &Address,
+) {
+    let owner = interact.wallet_address.clone();
+
+    // English: ilk teklif ölçülmez, geçilen teklif iade bakiyesine eklenir
+    gas.insert(
+        "createAuction".to_owned(),
+        gas_of!(
+            interact,
+            &owner,
+            create_auction(AuctionKind::English, 1u64, 0u64, BENCH_AUCTION_DURATION, OptionalValue::<u64>::None),
+            egld 1u64
+        ),
+    );
+    let english = interact.last_offer_id().await;
+    gas_of!(interact, buyer, bid(english), egld 1u64);
+    gas.insert("bid".to_owned(), gas_of!(interact, other, bid(english), egld 2u64));
+    gas.insert("getPendingRefund".to_owned(), gas_of!(interact, &owner, pending_refunds(buyer)));
+    gas.insert("withdrawRefund".to_owned(), gas_of!(interact, buyer, withdraw_refund()));
+
+    // Dutch: fiyat 10'dan 1'e düşer
+    gas_of!(
+        interact,
+        &owner,
+        create_auction(AuctionKind::Dutch, 10u64, 1u64, BENCH_REVEAL_DURATION, OptionalValue::<u64>::None),
+        egld 1u64
+    );
+    let dutch = interact.last_offer_id().await;
+    gas.insert("getDutchAuctionPrice".to_owned(), gas_of!(interact, &owner, get_dutch_auction_price(dutch)));
+    gas.insert("buy".to_owned(), gas_of!(interact, buyer, buy(dutch), egld 10u64));
+
+    // Sealed: teklif verme süresi bittikten sonra açıklama süresi başlar
+    gas_of!(
+        interact,
+        &owner,
+        create_auction(
+            AuctionKind::Sealed,
+            1u64,
+            0u64,
+            BENCH_AUCTION_DURATION,
+            OptionalValue::Some(BENCH_REVEAL_DURATION)
+        ),
+        egld 1u64
+    );
+    let sealed = interact.last_offer_id().await;
+    gas.insert(
+        "computeBidHash".to_owned(),
+        gas_of!(interact, &owner, compute_bid_hash(buyer, 5u64, "salt")),
+    );
+    let contract = interact.contract_address().clone();
+    let bid_hash = interact
+        .interactor
+        .query()
+        .to(&contract)
+        .typed(EscrowContractProxy)
+        .compute_bid_hash(buyer, 5u64, "salt")
+        .returns(ReturnsResult)
+        .run()
+        .await;
+    gas.insert("commitBid".to_owned(), gas_of!(interact, buyer, commit_bid(sealed, bid_hash), egld 5u64));
+
+    interact.interactor.generate_blocks(BLOCKS_PAST_AUCTION).await.unwrap();
+    gas.insert("settleAuction".to_owned(), gas_of!(interact, &owner, settle_auction(english)));
+    gas.insert("revealBid".to_owned(), gas_of!(interact, buyer, reveal_bid(sealed, 5u64, "salt")));
+}
+
+// Kısmi satışlı açık satış teklifi endpoint'leri ve view'ı
+async fn measure_sell_offer(interact: &mut ContractInteract, gas: &mut BTreeMap<String, u64>, buyer: &Address) {
+    let owner = interact.wallet_address.clone();
+
+    gas.insert(
+        "createSellOffer".to_owned(),
+        gas_of!(interact, &owner, create_sell_offer(10u64, 10u64), egld 100u64),
+    );
+    let sell_offer = interact.last_offer_id().await;
+    gas.insert("getFillPrice".to_owned(), gas_of!(interact, &owner, get_fill_price(sell_offer, 20u64)));
+    gas.insert("acceptPartial".to_owned(), gas_of!(interact, buyer, accept_partial(sell_offer, 20u64), egld 2u64));
+}
+
+fn print_table(report: &GasReport) {
+    let columns: Vec<&String> = report.keys().collect();
+    print!("{:<30}", "function");
+    for column in &columns {
+        print!("{:>16}", format!("N={column}"));
+    }
+    println!();
+
+    let functions = report.values().next().map(|gas| gas.keys().collect::<Vec<_>>()).unwrap_or_default();
+    for function in functions {
+        print!("{function:<30}");
+        for column in &columns {
+            print!("{:>16}", report[*column].get(function).copied().unwrap_or_default());
+        }
+        println!();
+    }
+}
+
+fn read_report(path: &str) -> Option<GasReport> {
+    let content = std::fs::read_to_string(path).ok()?;
+    Some(toml::from_str(&content).expect("invalid gas report file"))
+}
+
+fn write_report(path: &str, report: &GasReport) {
+    std::fs::write(path, toml::to_string(report).unwrap()).unwrap();
+}
+
+// Baseline'dan tolerans üzerinde artan ve baseline'da karşılığı olmayan ölçümleri döndürür
+// Yeni veya adı değişen bir endpoint baseline güncellenmeden kontrolsüz geçmemelidir
+fn regressions(report: &GasReport, baseline: &GasReport) -> Vec<String> {
+    let mut regressions = Vec::new();
+    for (offer_count, gas) in report {
+        let Some(baseline_gas) = baseline.get(offer_count) else {
+            regressions.push(format!("N={offer_count}: missing from baseline"));
+            continue;
+        };
+        for (function, used) in gas {
+            let Some(expected) = baseline_gas.get(function) else {
+                regressions.push(format!("{function} (N={offer_count}): missing from baseline"));
+                continue;
+            };
+            if *used * 100 > *expected * (100 + TOLERANCE_PERCENT) {
+                regressions.push(format!("{function} (N={offer_count}): {expected} -> {used}"));
+            }
+        }
+    }
+    regressions
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn gas_benchmark_cs_test() {
+    let mut interact = ContractInteract::new(Config::chain_simulator_config()).await;
+
+    let mut report = GasReport::new();
+    for offer_count in OFFER_COUNTS {
+        let gas = measure(&mut interact, offer_count).await;
+        report.insert(offer_count.to_string(), gas);
+    }
+
+    print_table(&report);
+    write_report(REPORT_FILE, &report);
+
+    if std::env::var(UPDATE_BASELINE_ENV).is_ok_and(|value| value == "1") {
+        write_report(BASELINE_FILE, &report);
+        println!("baseline written to {BASELINE_FILE}");
+        return;
+    }
+
+    // Eksik baseline ile test geçerse gerilemeler fark edilmez
+    let Some(baseline) = read_report(BASELINE_FILE) else {
+        panic!("{BASELINE_FILE} is missing, run the benchmark with {UPDATE_BASELINE_ENV}=1 to record it");
+    };
+    let regressions = regressions(&report, &baseline);
+    assert!(
+        regressions.is_empty(),
+        "gas regressed more than {TOLERANCE_PERCENT}% over {BASELINE_FILE} or is missing from it \
+         (run with {UPDATE_BASELINE_ENV}=1 after an intended change):\n{}",
+        regressions.join("\n")
+    );
+}
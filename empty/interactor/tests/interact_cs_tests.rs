@@ -0,0 +1,48 @@
+use empty_interact::{Config, ContractInteract};
+use multiversx_sc_snippets::imports::*;
+
+// Bu testler yerel bir chain simulator gerektirir (http://localhost:8085)
+// Çalıştırmak için:
+// - simulator'ı başlatın: sc-meta cs start
+// - kontratı derleyin: sc-meta all build
+// - cargo test --features chain-simulator-tests
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn deploy_create_accept_cs_test() {
+    let mut interact = ContractInteract::new(Config::chain_simulator_config()).await;
+    let buyer = interact.register_wallet(test_wallets::bob()).await;
+
+    interact.deploy().await;
+    interact
+        .create(&buyer.clone().into(), 1_000, None, Some("invoice-1"), None, None, None, None, false)
+        .await;
+    let offer_id = interact.last_offer_id().await;
+
+    interact.accept_offer_from(&buyer, offer_id, None).await;
+    assert_eq!(interact.user_offers(&interact.wallet_address.clone().into()).await, vec![offer_id]);
+
+    let offer = interact.offer(offer_id).await.unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+
+    interact
+        .accept_offer_expect_err(&buyer, offer_id, "E003: Offer not active")
+        .await;
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn deploy_create_cancel_cs_test() {
+    let mut interact = ContractInteract::new(Config::chain_simulator_config()).await;
+    let buyer = interact.register_wallet(test_wallets::bob()).await;
+
+    interact.deploy().await;
+    interact.create(&buyer.clone().into(), 1_000, None, None, None, None, None, None, false).await;
+    let offer_id = interact.last_offer_id().await;
+
+    interact.cancel_offer(offer_id).await;
+
+    let offer = interact.offer(offer_id).await.unwrap();
+    assert!(offer.status == empty::OfferStatus::Cancelled);
+    assert!(interact.active_offers().await.is_empty());
+}
@@ -0,0 +1,25 @@
+#![no_std]
+multiversx_sc::imports!();
+
+// Escrow serbest bırakma koşullarını test etmek için basit bir DAO taklidi
+// Teklifler koşul olarak isProposalPassed view'ını kullanır
+// setProposalPassed ile önerinin sonucu testlerde doğrudan belirlenir
+#[multiversx_sc::contract]
+pub trait DaoMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // Önerinin kabul edilip edilmediğini ayarlar
+    #[endpoint(setProposalPassed)]
+    fn set_proposal_passed(&self, proposal_id: u64, passed: bool) {
+        self.proposal_passed(proposal_id).set(passed);
+    }
+
+    // Öneri kabul edildiyse true döner
+    #[view(isProposalPassed)]
+    #[storage_mapper("proposalPassed")]
+    fn proposal_passed(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+}
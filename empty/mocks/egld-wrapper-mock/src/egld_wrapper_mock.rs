@@ -0,0 +1,33 @@
+#![no_std]
+multiversx_sc::imports!();
+
+// Escrow takaslarını test etmek için EGLD wrapper taklidi
+// wrapEgld gerçek wrapper gibi gelen EGLD karşılığında aynı miktarda WEGLD gönderir
+// WEGLD kontratın bakiyesinden ödenir, bakiye testte hesap durumu ile verilir
+#[multiversx_sc::contract]
+pub trait EgldWrapperMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint(setWrappedEgldToken)]
+    fn set_wrapped_egld_token(&self, token: TokenIdentifier) {
+        self.wrapped_egld_token().set(token);
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(wrapEgld)]
+    fn wrap_egld(&self) -> EsdtTokenPayment {
+        let amount = self.call_value().egld().clone_value();
+        let payment = EsdtTokenPayment::new(self.wrapped_egld_token().get(), 0, amount);
+
+        self.tx().to(ToCaller).payment(&payment).transfer();
+        payment
+    }
+
+    #[view(getWrappedEgldTokenId)]
+    #[storage_mapper("wrappedEgldToken")]
+    fn wrapped_egld_token(&self) -> SingleValueMapper<TokenIdentifier>;
+}
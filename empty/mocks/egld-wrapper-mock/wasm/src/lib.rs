@@ -0,0 +1,29 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            3
+// Async Callback (empty):               1
+// Total number of exported functions:   6
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    egld_wrapper_mock
+    (
+        init => init
+        upgrade => upgrade
+        setWrappedEgldToken => set_wrapped_egld_token
+        wrapEgld => wrap_egld
+        getWrappedEgldTokenId => wrapped_egld_token
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,50 @@
+#![no_std]
+multiversx_sc::imports!();
+
+// Escrow likit staking modunu test etmek için MultiversX liquid staking kontratı taklidi
+// addLiquidity ve getLsValueForPosition gerçek kontrat ile aynı imzaya sahiptir
+// Bir LST'nin EGLD değeri testte sabit bir oranla belirlenir, oran artırılarak getiri oluşturulur
+// LST kontratın bakiyesinden ödenir, bakiye testte hesap durumu ile verilir
+#[multiversx_sc::contract]
+pub trait LiquidStakingMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint(setLsToken)]
+    fn set_ls_token(&self, ls_token: TokenIdentifier) {
+        self.ls_token().set(ls_token);
+    }
+
+    // Bir LST'nin EGLD değeri: numerator / denominator
+    #[endpoint(setExchangeRate)]
+    fn set_exchange_rate(&self, numerator: BigUint, denominator: BigUint) {
+        self.exchange_rate().set((numerator, denominator));
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(addLiquidity)]
+    fn add_liquidity(&self) -> EsdtTokenPayment {
+        let payment = self.call_value().egld();
+        let (numerator, denominator) = self.exchange_rate().get();
+
+        let ls_amount = &*payment * &denominator / &numerator;
+        let output = EsdtTokenPayment::new(self.ls_token().get(), 0, ls_amount);
+        self.tx().to(ToCaller).payment(&output).transfer();
+        output
+    }
+
+    #[view(getLsValueForPosition)]
+    fn get_ls_value_for_position(&self, ls_token_amount: BigUint) -> BigUint {
+        let (numerator, denominator) = self.exchange_rate().get();
+        ls_token_amount * numerator / denominator
+    }
+
+    #[storage_mapper("lsToken")]
+    fn ls_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("exchangeRate")]
+    fn exchange_rate(&self) -> SingleValueMapper<(BigUint, BigUint)>;
+}
@@ -0,0 +1,30 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            4
+// Async Callback (empty):               1
+// Total number of exported functions:   7
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    liquid_staking_mock
+    (
+        init => init
+        upgrade => upgrade
+        setLsToken => set_ls_token
+        setExchangeRate => set_exchange_rate
+        addLiquidity => add_liquidity
+        getLsValueForPosition => get_ls_value_for_position
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,37 @@
+#![no_std]
+multiversx_sc::imports!();
+
+// Escrow takaslarını test etmek için xExchange pair taklidi
+// swapTokensFixedInput gerçek pair ile aynı imzaya sahiptir, fiyat ise testte sabit bir oranla belirlenir
+// Çıktı tokenı kontratın bakiyesinden ödenir, bakiye testte hesap durumu ile verilir
+#[multiversx_sc::contract]
+pub trait PairMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // Bir birim girdi için verilecek çıktı: numerator / denominator
+    #[endpoint(setExchangeRate)]
+    fn set_exchange_rate(&self, numerator: BigUint, denominator: BigUint) {
+        self.exchange_rate().set((numerator, denominator));
+    }
+
+    #[payable("*")]
+    #[endpoint(swapTokensFixedInput)]
+    fn swap_tokens_fixed_input(&self, token_out: TokenIdentifier, amount_out_min: BigUint) -> EsdtTokenPayment {
+        let payment = self.call_value().single_esdt().clone();
+        let (numerator, denominator) = self.exchange_rate().get();
+
+        let amount_out = &payment.amount * &numerator / &denominator;
+        require!(amount_out >= amount_out_min, "Slippage exceeded");
+
+        let output = EsdtTokenPayment::new(token_out, 0, amount_out);
+        self.tx().to(ToCaller).payment(&output).transfer();
+        output
+    }
+
+    #[storage_mapper("exchangeRate")]
+    fn exchange_rate(&self) -> SingleValueMapper<(BigUint, BigUint)>;
+}
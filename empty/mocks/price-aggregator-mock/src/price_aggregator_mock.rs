@@ -0,0 +1,45 @@
+#![no_std]
+multiversx_sc::imports!();
+
+// USD cinsinden teklifleri test etmek için MultiversX price aggregator taklidi
+// latestPriceFeed view'ı gerçek aggregator ile aynı sonuçları döndürür
+// Fiyatlar oracle turları yerine setLatestPriceFeed ile doğrudan yazılır
+#[multiversx_sc::contract]
+pub trait PriceAggregatorMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // Çiftin fiyatını günceller, zaman damgası olarak blok zamanı kullanılır
+    #[endpoint(setLatestPriceFeed)]
+    fn set_latest_price_feed(&self, from: ManagedBuffer, to: ManagedBuffer, price: BigUint, decimals: u8) {
+        let round_id = self.round_id().update(|round_id| {
+            *round_id += 1;
+            *round_id
+        });
+        let timestamp = self.blockchain().get_block_timestamp();
+
+        self.price_feed(&from, &to).set((round_id, timestamp, price, decimals));
+    }
+
+    // (tur, from, to, zaman damgası, fiyat, ondalık basamak sayısı)
+    #[view(latestPriceFeed)]
+    fn latest_price_feed(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValue6<u32, ManagedBuffer, ManagedBuffer, u64, BigUint, u8> {
+        require!(!self.price_feed(&from, &to).is_empty(), "token pair not found");
+
+        let (round_id, timestamp, price, decimals) = self.price_feed(&from, &to).get();
+        (round_id, from, to, timestamp, price, decimals).into()
+    }
+
+    #[storage_mapper("priceFeed")]
+    fn price_feed(&self, from: &ManagedBuffer, to: &ManagedBuffer) -> SingleValueMapper<(u32, u64, BigUint, u8)>;
+
+    #[storage_mapper("roundId")]
+    fn round_id(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,38 @@
+#![no_std]
+multiversx_sc::imports!();
+
+// Escrow teslimat çağrılarını test etmek için alıcı kontrat (ör. marketplace) taklidi
+// Teslimat endpoint'i gelen EGLD'yi sipariş numarasına göre kaydeder
+// setRejecting(true) ile teslimatlar reddedilerek başarısız teslimat senaryosu test edilebilir
+#[multiversx_sc::contract]
+pub trait RecipientMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // Escrow kontratının teslimat çağrısı ile çağırdığı endpoint
+    #[payable("EGLD")]
+    #[endpoint(onEscrowDelivery)]
+    fn on_escrow_delivery(&self, order_id: u64) {
+        require!(!self.rejecting().get(), "Delivery rejected");
+
+        let payment = self.call_value().egld();
+        self.received(order_id).update(|received| *received += &*payment);
+    }
+
+    // Teslimatların reddedilip reddedilmeyeceğini ayarlar
+    #[endpoint(setRejecting)]
+    fn set_rejecting(&self, rejecting: bool) {
+        self.rejecting().set(rejecting);
+    }
+
+    // Sipariş numarasına göre teslim alınan toplam miktar
+    #[view(getReceived)]
+    #[storage_mapper("received")]
+    fn received(&self, order_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("rejecting")]
+    fn rejecting(&self) -> SingleValueMapper<bool>;
+}
@@ -1,15 +1,20 @@
 #![no_std]
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
+
+pub mod empty_proxy;
+pub mod errors;
+
+use errors::*;
+
+#[type_abi]
 #[derive(
-    multiversx_sc::codec::derive::TopEncode,
-    multiversx_sc::codec::derive::TopDecode,
+    TopEncode,
+    TopDecode,
     
-    multiversx_sc::codec::derive::NestedEncode, 
-    multiversx_sc::codec::derive::NestedDecode,
+    NestedEncode, 
+    NestedDecode,
 
-    multiversx_sc::derive::TypeAbi,
-    
     PartialEq,
     Clone
 )]
@@ -17,27 +22,522 @@ multiversx_sc::derive_imports!();
 pub enum OfferStatus {
     Active,
     Completed,
-    Cancelled
+    Cancelled,
+    PendingDelivery // Fonlar alıcı kontrata teslimat çağrısı ile gönderiliyor veya çağrı başarısız oldu
 }
 
+impl OfferStatus {
+    // Teklif kabul veya iptal ile sonuçlanmış mı
+    // PendingDelivery teklifinin fonları hala kontratta olduğu için sonuçlanmış sayılmaz
+    pub fn is_closed(&self) -> bool {
+        matches!(self, OfferStatus::Completed | OfferStatus::Cancelled)
+    }
+}
+
+// Offer'ın storage'daki kodlaması versiyonludur
+// TopDecode elle yazılmıştır, böylece versiyon alanı olmayan eski kayıtlar da okunabilir
+// TopEncode de elle yazılmıştır, kayıt her zaman güncel versiyonla ve tüm alanlarıyla kodlanır
+#[type_abi]
 #[derive(
-    multiversx_sc::codec::derive::TopEncode,
-    multiversx_sc::codec::derive::TopDecode,
-    multiversx_sc::codec::derive::NestedEncode,
-    multiversx_sc::codec::derive::NestedDecode,
-    multiversx_sc::derive::TypeAbi,
+    NestedEncode,
+    NestedDecode,
     Clone
 )]
 
 
 pub struct Offer<M: ManagedTypeApi> {
 
+    pub version: u8, // Kodlama versiyonu, her zaman ilk alan olmalıdır
     pub offer_id: u64,
     pub creator: ManagedAddress<M>,
     pub recipient: ManagedAddress<M>,
     pub amount: BigUint<M>,
     pub status: OfferStatus,
-    pub created_timestamp: u64
+    pub created_timestamp: u64,
+    pub metadata: OfferMetadata<M>,
+    pub closed_timestamp: u64, // Teklifin kabul/iptal edildiği zaman, aktifken 0
+    pub closed_by: ManagedAddress<M>, // Teklifi kabul/iptal eden adres, aktifken veya bilinmiyorsa sıfır adres
+    pub remaining_amount: BigUint<M> // Kısmi satışlı tekliflerde henüz satılmamış miktar, diğer tekliflerde amount ile aynıdır
+}
+
+// Arşivlenmiş teklifin storage'da kalan özeti
+// Tam kayıt temizlendikten sonra teklifin nasıl sonuçlandığı bu özetten okunabilir
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct OfferSummary<M: ManagedTypeApi> {
+    pub offer_id: u64,
+    pub status: OfferStatus,
+    pub amount: BigUint<M>,     // Son transfer edilen / iade edilen miktar
+    pub closed_timestamp: u64
+}
+
+// Offer'ın güncel kodlama versiyonu
+// Offer'a yeni alan eklendiğinde bu değer artırılmalı ve alan TopDecode içinde
+// sadece yeni versiyondan itibaren okunacak şekilde eklenmelidir
+// Versiyon geçmişi:
+// - 1: ilk sürüm, versiyon byte'ı yok
+// - 2: metadata eklendi
+// - 3: closed_timestamp eklendi
+// - 4: closed_by eklendi
+// - 5: remaining_amount eklendi
+pub const OFFER_VERSION: u8 = 5;
+
+impl<M: ManagedTypeApi> TopEncode for Offer<M> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: multiversx_sc::codec::TopEncodeOutput,
+        H: multiversx_sc::codec::EncodeErrorHandler,
+    {
+        // version alanı okunan kaydın eski versiyonunu gösterebilir
+        // Yazılan veri ise her zaman güncel kodlamadadır, bu yüzden versiyon byte'ı olarak OFFER_VERSION yazılır
+        let mut buffer = output.start_nested_encode();
+        OFFER_VERSION.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.offer_id.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.creator.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.recipient.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.amount.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.status.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.created_timestamp.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.metadata.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.closed_timestamp.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.closed_by.dep_encode_or_handle_err(&mut buffer, h)?;
+        self.remaining_amount.dep_encode_or_handle_err(&mut buffer, h)?;
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for Offer<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: multiversx_sc::codec::TopDecodeInput,
+        H: multiversx_sc::codec::DecodeErrorHandler,
+    {
+        // Önce ham veriyi alıyoruz, ilk byte'a bakarak hangi kodlamanın kullanıldığını belirliyoruz
+        let buffer = ManagedBuffer::<M>::top_decode_or_handle_err(input, h)?;
+        let mut first_byte = [0u8; 1];
+        if buffer.load_slice(0, &mut first_byte).is_err() {
+            return Err(h.handle_error(DecodeError::INPUT_TOO_SHORT));
+        }
+
+        let mut nested_input = multiversx_sc::codec::TopDecodeInput::into_nested_buffer(buffer);
+
+        // İlk sürümün kayıtları versiyon byte'ı olmadan doğrudan offer_id ile başlar
+        // u64'ün ilk byte'ı pratikte her zaman 0'dır, versiyonlu kayıtlar ise en az 2 ile başlar
+        let version = if first_byte[0] == 0 {
+            1
+        } else {
+            u8::dep_decode_or_handle_err(&mut nested_input, h)?
+        };
+
+        let offer_id = u64::dep_decode_or_handle_err(&mut nested_input, h)?;
+        let creator = ManagedAddress::dep_decode_or_handle_err(&mut nested_input, h)?;
+        let recipient = ManagedAddress::dep_decode_or_handle_err(&mut nested_input, h)?;
+        let amount = BigUint::dep_decode_or_handle_err(&mut nested_input, h)?;
+        let status = OfferStatus::dep_decode_or_handle_err(&mut nested_input, h)?;
+        let created_timestamp = u64::dep_decode_or_handle_err(&mut nested_input, h)?;
+
+        // Sonraki versiyonlarda eklenen alanlar, eski kayıtlarda boş değer alır
+        let metadata = if version >= 2 {
+            OfferMetadata::dep_decode_or_handle_err(&mut nested_input, h)?
+        } else {
+            OfferMetadata {
+                memo: ManagedBuffer::new(),
+                reference: ManagedBuffer::new(),
+                terms_hash: ManagedBuffer::new()
+            }
+        };
+        let closed_timestamp = if version >= 3 {
+            u64::dep_decode_or_handle_err(&mut nested_input, h)?
+        } else {
+            0
+        };
+        let closed_by = if version >= 4 {
+            ManagedAddress::dep_decode_or_handle_err(&mut nested_input, h)?
+        } else {
+            ManagedAddress::zero()
+        };
+        // Kısmi satış öncesindeki tekliflerin tamamı satılmamış sayılır
+        let remaining_amount = if version >= 5 {
+            BigUint::dep_decode_or_handle_err(&mut nested_input, h)?
+        } else {
+            amount.clone()
+        };
+
+        if !multiversx_sc::codec::NestedDecodeInput::is_depleted(&nested_input) {
+            return Err(h.handle_error(DecodeError::INPUT_TOO_LONG));
+        }
+
+        // version alanı storage'daki kodlamayı gösterir, save_offer ile yazılınca güncellenir
+        Ok(Offer {
+            version,
+            offer_id,
+            creator,
+            recipient,
+            amount,
+            status,
+            created_timestamp,
+            metadata,
+            closed_timestamp,
+            closed_by,
+            remaining_amount
+        })
+    }
+}
+
+// Teklife eklenebilen isteğe bağlı bilgiler
+// Verilmeyen alanlar boş buffer olarak saklanır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct OfferMetadata<M: ManagedTypeApi> {
+    pub memo: ManagedBuffer<M>,       // Kısa açıklama notu
+    pub reference: ManagedBuffer<M>,  // Harici referans (ör. fatura numarası), oluşturan başına benzersiz
+    pub terms_hash: ManagedBuffer<M>  // Sözleşme şartları dokümanının hash'i
+}
+
+// Alıcı bir kontrat olduğunda fonların teslim edileceği endpoint çağrısı
+// Tanımlıysa kabul veya serbest bırakma sırasında fonlar bu endpoint'e argümanlarla birlikte gönderilir
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct DeliveryCall<M: ManagedTypeApi> {
+    pub endpoint: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub gas_limit: u64 // Alıcı kontrattaki çağrı için ayrılan gas
+}
+
+// Fonların serbest bırakılması için başka bir kontratın view'ına bağlı koşul
+// tryRelease çağrıldığında view verilen argümanlarla çağrılır
+// Dönen değer beklenen değerle birebir aynıysa fonlar alıcıya aktarılır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct ReleaseCondition<M: ManagedTypeApi> {
+    pub target: ManagedAddress<M>,                   // View'ı çağrılacak kontrat (escrow ile aynı shard'da olmalı)
+    pub view: ManagedBuffer<M>,                      // Çağrılacak view'ın adı
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,  // View'a gönderilecek ham argümanlar
+    pub expected: ManagedBuffer<M>                   // Beklenen ham (top-encoded) sonuç
+}
+
+// Likit staking modundaki teklifin pozisyonu
+// Kilitli EGLD oluşturma anında likit staking kontratına yatırılır ve karşılığında alınan LST tutulur
+// Teklif kapandığında LST'nin anapara kısmı fonları alan tarafa, getiri kısmı ise taraflar arasında paylaştırılır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct StakingPosition<M: ManagedTypeApi> {
+    pub liquid_staking: ManagedAddress<M>, // LST'nin alındığı kontrat, ayar sonradan değişse de değer okuma için bu kullanılır
+    pub ls_token: TokenIdentifier<M>,      // Likit staking tokenı (LST)
+    pub ls_amount: BigUint<M>,             // Teklif için tutulan LST miktarı
+    pub recipient_yield_share: u32         // Getiriden alıcıya düşen pay (baz puan), oluşturma anındaki ayardan alınır
+}
+
+// Açık artırma türü
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone
+)]
+pub enum AuctionKind {
+    English, // Teklifler artarak verilir, süre sonunda en yüksek teklif kazanır
+    Dutch,   // Fiyat süre boyunca doğrusal olarak düşer, ilk ödeyen kazanır
+    Sealed   // Teklifler hash olarak verilir ve ikinci bir sürede açıklanır, en yüksek açıklanan teklif kazanır
+}
+
+// Açık artırma teklifinin ayarları
+// Açık artırmalar da Offer olarak saklanır: amount satılan varlığın miktarıdır,
+// recipient ise en yüksek teklifi veren (English) veya satın alan (Dutch) adrestir, teklif yokken boştur
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct Auction<M: ManagedTypeApi> {
+    pub kind: AuctionKind,
+    pub token: EgldOrEsdtTokenIdentifier<M>,  // Satılan varlık (EGLD, ESDT veya NFT)
+    pub nonce: u64,
+    pub start_price: BigUint<M>,              // English: en düşük teklif, Dutch: başlangıç fiyatı (EGLD)
+    pub end_price: BigUint<M>,                // Dutch: süre sonundaki fiyat, diğerlerinde 0
+    pub deadline: u64,                        // Açık artırmanın (Sealed: teklif verme süresinin) bittiği zaman
+    pub reveal_deadline: u64,                 // Sealed: tekliflerin açıklanma süresinin bittiği zaman, diğerlerinde 0
+    pub unrevealed_penalty: u32               // Sealed: açıklanmayan teminattan kesilen pay (baz puan), oluşturma anındaki ayardan alınır
+}
+
+// Kapalı teklifli açık artırmada verilmiş ve henüz açıklanmamış teklif
+// Teminat teklif miktarını gizlemek için tekliften fazla olabilir, açıklamada fazlası iade edilir
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct SealedBid<M: ManagedTypeApi> {
+    pub bid_hash: ManagedByteArray<M, 32>,  // computeBidHash(bidder, amount, salt)
+    pub deposit: BigUint<M>
+}
+
+// Parça parça satılabilen açık satış teklifinin ayarları
+// Satış teklifleri de Offer olarak saklanır: amount satışa çıkarılan toplam miktar,
+// remaining_amount henüz satılmamış miktar, recipient ise son alım yapan adrestir
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct SellOffer<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,  // Satılan token
+    pub nonce: u64,
+    pub price: BigUint<M>,                    // Toplam miktarın EGLD cinsinden fiyatı, alımlar bu fiyatla orantılı ödenir
+    pub min_fill: BigUint<M>                  // Tek alımda alınabilecek en az miktar, kalan miktar bundan azsa kalanın tamamı alınabilir
+}
+
+// Teklif yaşam döngüsü eventlerinin data alanı
+// Tüm durum geçişleri (oluşturma, kabul, iptal, admin iadesi) aynı yapıyı kullanır
+// Teklif ID, oluşturan ve alıcı topic olarak, geri kalan her şey burada taşınır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct OfferEventData<M: ManagedTypeApi> {
+    pub payment: EgldOrEsdtTokenPayment<M>, // Kilitlenen / transfer edilen ödeme
+    pub old_status: Option<OfferStatus>,    // Geçişten önceki durum, oluşturmada None
+    pub new_status: OfferStatus,            // Geçişten sonraki durum
+    pub timestamp: u64,                     // Geçişin gerçekleştiği blok zamanı
+    pub metadata: OfferMetadata<M>
+}
+
+// usdOfferSettled eventinin data alanı
+// Teklif ID topic olarak, hesaplamada kullanılan değerler burada taşınır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct UsdOfferSettledEventData<M: ManagedTypeApi> {
+    pub usd_amount: BigUint<M>, // Alıcıya borçlu olunan USD miktarı (sent)
+    pub price: BigUint<M>,      // Oracle'dan okunan EGLD/USD fiyatı
+    pub refund: BigUint<M>      // Oluşturana iade edilen teminat fazlası
+}
+
+// stakingPositionClosed eventinin data alanı
+// Teklif ID ve LST topic olarak, LST cinsinden dağıtılan miktarlar burada taşınır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct StakingPositionClosedEventData<M: ManagedTypeApi> {
+    pub principal: BigUint<M>,       // Fonları alan tarafa giden anapara
+    pub creator_yield: BigUint<M>,   // Getiriden oluşturana düşen pay
+    pub recipient_yield: BigUint<M>  // Getiriden alıcıya düşen pay
+}
+
+// partialFill eventinin data alanı
+// Teklif ID ve alıcı topic olarak, alımın miktarları burada taşınır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct PartialFillEventData<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,           // Alınan token miktarı
+    pub price: BigUint<M>,            // Alım için ödenen EGLD
+    pub remaining_amount: BigUint<M>  // Alımdan sonra satışta kalan miktar
+}
+
+// Metadata alanları için üst sınırlar
+// Storage maliyetini ve event boyutunu sınırlı tutmak için kullanılır
+pub const MAX_MEMO_LENGTH: usize = 256;
+pub const MAX_REFERENCE_LENGTH: usize = 64;
+pub const MAX_TERMS_HASH_LENGTH: usize = 64;
+
+// migrateOffers ile tek çağrıda dönüştürülebilecek en fazla teklif sayısı
+// Gas limitini aşmamak için toplu dönüştürme bu sınırla parçalara bölünür
+pub const MAX_MIGRATION_BATCH: u64 = 100;
+
+// Sonuçlanan bir teklifin arşivlenebilmesi için geçmesi gereken varsayılan süre (saniye, 30 gün)
+pub const DEFAULT_ARCHIVE_RETENTION_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+// archiveOffers ile tek çağrıda arşivlenebilecek en fazla teklif sayısı
+pub const MAX_ARCHIVE_BATCH: usize = 100;
+
+// Sayfalı view'larda tek sayfada dönebilecek en fazla teklif sayısı
+pub const MAX_PAGE_SIZE: usize = 100;
+
+// Teslimat çağrısının sonucunu işleyen callback için ayrılan gas
+pub const DELIVERY_CALLBACK_GAS: u64 = 10_000_000;
+
+// Serbest bırakma koşulunun view çağrısı için ayrılan gas
+pub const RELEASE_CONDITION_GAS: u64 = 20_000_000;
+
+// USD cinsinden tekliflerde kullanılan fiyat akışı (MultiversX price aggregator çifti)
+pub const PRICE_FEED_BASE: &[u8] = b"EGLD";
+pub const PRICE_FEED_QUOTE: &[u8] = b"USD";
+// USD miktarları sent cinsinden tutulur
+pub const USD_DECIMALS: u32 = 2;
+pub const EGLD_DECIMALS: u32 = 18;
+// Fiyat oracle'ının view çağrısı için ayrılan gas
+pub const PRICE_ORACLE_GAS: u64 = 20_000_000;
+// Kabul edilen en eski fiyatın yaşı için üst sınır (1 gün)
+pub const MAX_PRICE_AGE_LIMIT: u64 = 24 * 60 * 60;
+
+// EGLD'nin sarılması ve swap çağrıları için ayrılan gas
+pub const SWAP_GAS: u64 = 30_000_000;
+
+// Likit staking kontratına yapılan çağrılar için ayrılan gas
+pub const LIQUID_STAKING_GAS: u64 = 30_000_000;
+// Getiri payları ve cezalar için baz puan paydası
+pub const MAX_BASIS_POINTS: u32 = 10_000;
+
+// Kapalı teklifli açık artırmada verilebilecek en fazla teklif sayısı
+// Açıklanmayan teklifler settleAuction içinde tek seferde işlendiği için sınırlıdır
+pub const MAX_SEALED_BIDS: usize = 100;
+
+// Bir token için kabul edilen teklif miktarı aralığı
+// max_amount 0 ise üst sınır yoktur
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct TokenLimits<M: ManagedTypeApi> {
+    pub min_amount: BigUint<M>,
+    pub max_amount: BigUint<M>
+}
+
+// Bir token için toplam istatistikler
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone
+)]
+pub struct TokenStats<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub value_locked: BigUint<M>, // Aktif tekliflerde kilitli toplam miktar
+    pub volume: BigUint<M>        // Kabul edilen tekliflerle alıcılara aktarılan toplam miktar
+}
+
+// getStats view'ının döndürdüğü genel istatistikler
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Clone
+)]
+pub struct EscrowStats<M: ManagedTypeApi> {
+    pub total_created: u64,
+    pub active_count: u64,
+    pub completed_count: u64,
+    pub cancelled_count: u64,
+    pub tokens: ManagedVec<M, TokenStats<M>>
+}
+
+// Adres bazında işlem geçmişi sayaçları
+// Alıcılar bir satıcının teklif iptal etme geçmişini bu sayaçlardan görebilir
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    Default,
+    Clone
+)]
+pub struct UserReputation {
+    pub offers_created: u64,
+    pub completed_as_creator: u64,  // Oluşturduğu ve alıcı tarafından kabul edilen teklifler
+    pub cancelled_as_creator: u64,  // Oluşturduğu ve kendisinin iptal ettiği teklifler
+    pub accepted_as_recipient: u64, // Alıcı olarak kabul ettiği teklifler
+    pub disputes_lost: u64          // Kaybedilen anlaşmazlıklar, anlaşmazlık akışı eklenene kadar 0 kalır
+}
+
+impl UserReputation {
+    // Başarıyla tamamlanan toplam işlem sayısı (oluşturan veya alıcı olarak)
+    pub fn completed_trades(&self) -> u64 {
+        self.completed_as_creator + self.accepted_as_recipient
+    }
+}
+
+// Yetkili işlemler için roller
+// Kontrat sahibi (owner) tüm rollere sahip kabul edilir
+// Admin rolü diğer rolleri verip alabilir, Admin rolünün verilmesi iki adımlıdır
+#[type_abi]
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone,
+    Copy
+)]
+pub enum Role {
+    Admin,
+    Pauser,
+    FeeManager,
+    Arbiter,
+    ComplianceOfficer
 }
 
 
@@ -57,6 +557,37 @@ pub trait EscrowContract {
         // - u64 tipi negatif değer alamaz
         // - 8 byte ile çok büyük pozitif sayıları tutabilir
         self.last_offer_id().set_if_empty(0u64);
+
+        // Yeni deploy edilen kontratta eski kodlamalı teklif yoktur
+        self.versioned_storage().set(true);
+
+        self.archive_retention_period().set_if_empty(DEFAULT_ARCHIVE_RETENTION_PERIOD);
+
+        // İstatistikler deploy anından itibaren tüm teklifleri kapsar
+        self.stats_first_offer_id().set_if_empty(1u64);
+    }
+
+    // Kontrat güncellendiğinde (upgrade) çalışan fonksiyon
+    // Versiyonsuz ilk sürümden güncellenirken, o ana kadar oluşturulan teklifler eski kodlamadadır
+    // Bu teklifler okunurken otomatik dönüştürülür, storage'a ise yazıldıkları anda
+    // veya migrateOffers ile toplu olarak güncel kodlamada kaydedilir
+    #[upgrade]
+    fn upgrade(&self) {
+        // Bayrak sadece versiyonsuz ilk sürümde boştur, sonraki upgrade'lerde eski teklifler tekrar sayılmaz
+        // legacy_offer_count'un boş olup olmadığına bakılamaz, çünkü 0 yazıldığında storage boş kalır
+        if !self.versioned_storage().get() {
+            let last_offer_id = self.last_offer_id().get();
+            self.legacy_offer_count().set(last_offer_id);
+            self.legacy_offers_remaining().set(last_offer_id);
+            self.versioned_storage().set(true);
+        }
+
+        self.archive_retention_period().set_if_empty(DEFAULT_ARCHIVE_RETENTION_PERIOD);
+
+        // İstatistikler eklenmeden önce oluşturulan teklifler sayaçlara dahil edilmez
+        // Aksi halde bu teklifler kapanırken sayaçlar eksiye düşerdi
+        let last_offer_id = self.last_offer_id().get();
+        self.stats_first_offer_id().set_if_empty(last_offer_id + 1);
     }
 
     // create fonksiyonu yeni bir escrow teklifi oluşturur
@@ -68,22 +599,43 @@ pub trait EscrowContract {
     // - Fonksiyonu dışarıdan çağrılabilir yapar
     // - ABI'de görünmesini sağlar
     // - Gas limitlerini ayarlar
+    // #[allow_multiple_var_args] makrosu:
+    // - Birden fazla isteğe bağlı (OptionalValue) argümana izin verir
+    // - Argümanlar sırayla okunur, sondaki argümanlar verilmeyebilir
     #[payable("EGLD")]
     #[endpoint]
-    fn create(&self, buyer: ManagedAddress) {
+    #[allow_multiple_var_args]
+    fn create(
+        &self,
+        buyer: ManagedAddress,
+        // Metadata argümanları isteğe bağlıdır
+        // OptionalValue sayesinde eski çağrılar (sadece buyer ile) çalışmaya devam eder
+        opt_memo: OptionalValue<ManagedBuffer>,
+        opt_reference: OptionalValue<ManagedBuffer>,
+        opt_terms_hash: OptionalValue<ManagedBuffer>,
+        // Alıcının sahip olması gereken en az tamamlanmış işlem sayısı (isteğe bağlı)
+        opt_min_recipient_completed_trades: OptionalValue<u64>,
+        // Fonların tryRelease ile serbest bırakılabilmesi için koşul (isteğe bağlı)
+        // Option içinde alınır, böylece sonraki argüman verildiğinde boş gönderilerek atlanabilir
+        opt_release_condition: OptionalValue<Option<ReleaseCondition<Self::Api>>>,
+        // Alıcıya borçlu olunan USD miktarı, sent cinsinden (isteğe bağlı, 0 ise teklif EGLD cinsindendir)
+        opt_usd_amount: OptionalValue<BigUint>,
+        // true ise kilitli EGLD likit staking kontratına yatırılır (isteğe bağlı, varsayılan false)
+        opt_stake: OptionalValue<bool>
+    ) {
         // Gönderilen EGLD miktarını alıyoruz
         // call_value() kullanıyoruz çünkü:
         // - Güvenli bir şekilde ödeme miktarını alır
         // - Ödeme tipini kontrol eder
         // - Gas optimizasyonu sağlar
-        let payment = self.call_value().egld_value();
+        let payment = self.call_value().egld();
         
         // Ödeme miktarının 0'dan büyük olduğunu kontrol ediyoruz
         // require! makrosu kullanıyoruz çünkü:
         // - Koşul sağlanmazsa işlemi otomatik olarak geri alır (revert)
         // - Hata mesajı döndürür
         // - Gas'i optimize eder
-        require!(payment.clone_value() > BigUint::zero(), "Must pay more than 0");
+        require!(*payment > 0u32, ERR_PAYMENT_ZERO);
     
         // İşlemi başlatan adresi (satıcı) alıyoruz
         // blockchain().get_caller() kullanıyoruz çünkü:
@@ -91,6 +643,62 @@ pub trait EscrowContract {
         // - Spoofing'e karşı koruma sağlar
         // - Blockchain context'ine güvenli erişim sağlar
         let seller = self.blockchain().get_caller();
+
+        // Engellenmiş (yaptırım listesindeki) adresler teklif oluşturamaz ve teklif alamaz
+        require!(!self.blocked_addresses().contains(&seller), ERR_CREATOR_BLOCKED);
+        require!(!self.blocked_addresses().contains(&buyer), ERR_RECIPIENT_BLOCKED);
+
+        // Kendine teklif oluşturup kabul ederek itibar sayaçlarının şişirilmesini engeller
+        require!(buyer != seller, ERR_SELF_OFFER);
+
+        self.require_token_accepted(&EgldOrEsdtTokenIdentifier::egld(), &payment);
+
+        // Oluşturan isterse alıcının yeterli işlem geçmişine sahip olmasını şart koşabilir
+        if let OptionalValue::Some(min_completed_trades) = opt_min_recipient_completed_trades {
+            require!(
+                self.get_user_reputation(buyer.clone()).completed_trades() >= min_completed_trades,
+                ERR_RECIPIENT_REPUTATION_TOO_LOW
+            );
+        }
+
+        // Koşul view'ı senkron çağrılacağı için hedef kontrat aynı shard'da olmalıdır
+        let release_condition = opt_release_condition.into_option().flatten();
+        if let Some(condition) = &release_condition {
+            self.require_valid_release_condition(condition);
+        }
+
+        // USD cinsinden tekliflerde ödeme miktarı serbest bırakma anında oracle fiyatıyla hesaplanır
+        let usd_amount = opt_usd_amount.into_option().unwrap_or_default();
+        if usd_amount > 0u32 {
+            require!(!self.price_oracle().is_empty(), ERR_PRICE_ORACLE_NOT_SET);
+        }
+
+        // Likit staking modunda ödeme LST olarak yapıldığı için USD cinsinden tekliflerle birlikte kullanılamaz
+        let stake = opt_stake.into_option().unwrap_or_default();
+        if stake {
+            require!(!self.liquid_staking().is_empty(), ERR_LIQUID_STAKING_NOT_SET);
+            require!(usd_amount == 0u32, ERR_NOT_SUPPORTED_FOR_STAKED_OFFER);
+        }
+
+        // Verilmeyen metadata alanlarını boş buffer olarak alıyoruz
+        let metadata = OfferMetadata {
+            memo: opt_memo.into_option().unwrap_or_default(),
+            reference: opt_reference.into_option().unwrap_or_default(),
+            terms_hash: opt_terms_hash.into_option().unwrap_or_default()
+        };
+        require!(metadata.memo.len() <= MAX_MEMO_LENGTH, ERR_MEMO_TOO_LONG);
+        require!(metadata.reference.len() <= MAX_REFERENCE_LENGTH, ERR_REFERENCE_TOO_LONG);
+        require!(metadata.terms_hash.len() <= MAX_TERMS_HASH_LENGTH, ERR_TERMS_HASH_TOO_LONG);
+
+        // Referans aynı oluşturan için benzersiz olmalıdır
+        // Bu sayede aynı fatura için tekrar gönderilen işlem ikinci bir teklif açmaz
+        // (işlem geri alınır ve EGLD iade edilir)
+        if !metadata.reference.is_empty() {
+            require!(
+                self.offer_by_reference(&seller, &metadata.reference).is_empty(),
+                ERR_REFERENCE_ALREADY_USED
+            );
+        }
         
         // Yeni teklif ID'si oluşturuyoruz
         // Mevcut son ID'yi alıp 1 artırıyoruz çünkü:
@@ -110,12 +718,17 @@ pub trait EscrowContract {
         // - Type-safety sağlar
         // - Veri bütünlüğünü korur
         let offer = Offer {
+            version: OFFER_VERSION,
             offer_id: new_offer_id,
             creator: seller.clone(), // clone() ile güvenli kopya alıyoruz
             recipient: buyer.clone(),
             amount: payment.clone_value(),
+            remaining_amount: payment.clone_value(),
             status: OfferStatus::Active, // Başlangıç durumu Active
-            created_timestamp: self.blockchain().get_block_timestamp() // Zaman damgası
+            created_timestamp: self.blockchain().get_block_timestamp(), // Zaman damgası
+            metadata,
+            closed_timestamp: 0, // Teklif sonuçlanınca güncellenir
+            closed_by: ManagedAddress::zero()
         };
     
         // Teklifi blockchain'e kaydediyoruz
@@ -137,13 +750,33 @@ pub trait EscrowContract {
         // - Alıcı kendi gelen tekliflerini kolayca görebilir
         // - İndeksleme ve filtreleme kolaylaşır
         self.user_incoming_offers(&buyer).insert(new_offer_id);
+
+        // Referans verildiyse (oluşturan, referans) -> teklif ID eşlemesini kaydediyoruz
+        if !offer.metadata.reference.is_empty() {
+            self.offer_by_reference(&seller, &offer.metadata.reference).set(new_offer_id);
+        }
+
+        if let Some(condition) = release_condition {
+            self.release_condition(new_offer_id).set(condition);
+        }
+
+        if usd_amount > 0u32 {
+            self.usd_amount(new_offer_id).set(usd_amount);
+        }
+
+        if stake {
+            self.stake_offer_funds(&offer);
+        }
     
         // Teklif oluşturma olayını yayınlıyoruz
         // Event kullanıyoruz çünkü:
         // - Dış sistemler işlemi takip edebilir
         // - Frontend uygulamalar güncellenebilir
         // - İşlem geçmişi blockchain'de loglanır
-        self.create_offer_event(new_offer_id, &seller, &buyer, &payment);
+        self.create_offer_event(new_offer_id, &seller, &buyer, &self.offer_event_data(&offer, None));
+
+        self.record_offer_created(&offer);
+        self.update_reputation(&seller, |reputation| reputation.offers_created += 1);
     }
     #[endpoint(cancelOffer)]
     fn cancel_offer(
@@ -153,7 +786,7 @@ pub trait EscrowContract {
         // offer_id parametresi, iptal edilecek teklifin benzersiz kimlik numarasıdır
         // u64 tipi kullanılır çünkü negatif ID olamaz ve 64-bit yeterli büyüklüktedir
         offer_id: u64
-    ) -> SCResult<()> { // SCResult dönüş tipi, akıllı kontrat işlemlerinde hata yönetimi için kullanılır
+    ) {
         
         // blockchain().get_caller() fonksiyonu çağrıyı yapan adresi güvenli şekilde alır
         // Bu kritik bir güvenlik kontrolüdür - her zaman gerçek çağıranı doğru tespit etmeliyiz
@@ -161,93 +794,1545 @@ pub trait EscrowContract {
         
         // offer() storage mapper'ı ile blockchain'den teklif bilgilerini okuyoruz
         // mut keyword'ü değişkeni değiştirilebilir yapar çünkü sonra status'ü değiştireceğiz
+        // Var olmayan bir ID için anlamlı bir hata dönmek adına önce kaydın varlığını kontrol ediyoruz
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
         let mut offer = self.offer(offer_id).get();
 
         // require! makrosu ile kritik iş mantığı kontrollerini yapıyoruz
         // Bu kontroller başarısız olursa işlem geri alınır ve EGLD iade edilir
         // Aktif olmayan teklif iptal edilemez - bu mantıksal bir gerekliliktir
-        require!(offer.status == OfferStatus::Active, "Offer not active");
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
         
         // Sadece teklifi oluşturan kişi iptal edebilir
         // Bu önemli bir güvenlik kontrolüdür - başkasının teklifini iptal edemezsiniz
-        require!(offer.creator == caller, "Not offer creator");
+        require!(offer.creator == caller, ERR_NOT_OFFER_CREATOR);
+
+        // Engellenmiş bir adres kendi teklifini iptal ederek fonları geri alamaz
+        // Bu tekliflerin iadesi sadece owner incelemesi ile adminRefundOffer üzerinden yapılır
+        require!(!self.blocked_addresses().contains(&caller), ERR_CREATOR_BLOCKED);
+
+        // English ve Sealed açık artırmalar teklif aldıktan sonra iptal edilemez, teklif verenler süre sonuna güvenir
+        if !self.auction(offer_id).is_empty() {
+            let auction = self.auction(offer_id).get();
+            require!(
+                auction.kind == AuctionKind::Dutch
+                    || (self.highest_bid(offer_id).is_empty() && self.sealed_bids(offer_id).is_empty()),
+                ERR_AUCTION_HAS_BIDS
+            );
+        }
 
         // Teklifin durumunu Cancelled olarak güncelliyoruz
         // Enum kullanımı type-safety sağlar, geçersiz durumlar oluşamaz
+        let old_status = offer.status.clone();
         offer.status = OfferStatus::Cancelled;
+        offer.closed_timestamp = self.blockchain().get_block_timestamp();
+        offer.closed_by = caller.clone();
         
         // Güncellenmiş teklifi blockchain'e kaydediyoruz
-        // set() fonksiyonu storage'ı güvenli şekilde günceller
-        self.offer(offer_id).set(&offer);
+        // save_offer() storage'ı güncel kodlamada günceller
+        self.save_offer(&mut offer);
 
         // send().direct_egld() ile kilitli EGLD'yi teklif sahibine geri gönderiyoruz
         // Bu transfer güvenli ve atomiktir - ya tamamen başarılı olur ya da işlem geri alınır
-        self.send().direct_egld(&caller, &offer.amount);
+        // Likit staking modunda EGLD yerine LST gönderilir ve getiri taraflar arasında paylaştırılır
+        // Açık artırmalarda satılan varlık, satış tekliflerinde satılmamış kalan miktar geri gönderilir
+        if !self.auction(offer_id).is_empty() {
+            self.send_auction_asset(&offer, &caller);
+        } else if !self.sell_offer(offer_id).is_empty() {
+            self.send_sell_offer_remainder(&offer, &caller);
+        } else if self.staking_position(offer_id).is_empty() {
+            self.send().direct_egld(&caller, &offer.amount);
+        } else {
+            self.close_staking_position(&offer, &caller, true);
+        }
         
         // İptal işlemini blockchain'de logluyoruz
         // Bu sayede frontend uygulamalar ve kullanıcılar işlemi takip edebilir
-        self.cancel_offer_event(offer_id, &caller, &offer.amount);
+        self.cancel_offer_event(
+            offer_id,
+            &offer.creator,
+            &offer.recipient,
+            &self.offer_event_data(&offer, Some(old_status))
+        );
+
+        self.record_offer_closed(&offer);
+        self.update_reputation(&caller, |reputation| reputation.cancelled_as_creator += 1);
+    }
+
+    // Teklifi kabul etme fonksiyonu - Bu endpoint sayesinde alıcı kendisine gelen teklifi kabul edebilir
+    // #[endpoint(acceptOffer)] attribute'u bu fonksiyonun blockchain üzerinden çağrılabilir olduğunu belirtir
+    // acceptOffer ismi, fonksiyonun blockchain üzerinden çağrılırken kullanılacak public ismidir
+    #[endpoint(acceptOffer)]
+    fn accept_offer(
+        // &self parametresi, Rust'ta nesne yönelimli programlamada instance metodlarını belirtir
+        // Bu sayede kontrat storage'ına güvenli erişim sağlanır
+        &self,
+        // offer_id: Kabul edilecek teklifin benzersiz kimlik numarası
+        // u64 tipi kullanılır çünkü negatif ID olamaz ve 64-bit yeterli büyüklüktedir
+        offer_id: u64,
+        // İsteğe bağlı: ödemenin takas edileceği token ve kabul edilen en az çıktı miktarı
+        // Verilmezse ödeme EGLD olarak yapılır
+        opt_swap: OptionalValue<MultiValue2<TokenIdentifier, BigUint>>
+    ) {
+        
+        // blockchain().get_caller() fonksiyonu çağrıyı yapan adresi güvenli şekilde alır
+        // Bu kritik bir güvenlik kontrolüdür - her zaman gerçek çağıranı doğru tespit etmeliyiz
+        // caller değişkeni immutable olarak tanımlanır çünkü değiştirilmemesi gerekir
+        let caller = self.blockchain().get_caller();
+
+        // offer() storage mapper'ı ile blockchain'den teklif bilgilerini okuyoruz
+        // mut keyword'ü değişkeni değiştirilebilir yapar çünkü sonra status'ü değiştireceğiz
+        // get() fonksiyonu storage'dan veriyi okur ve deserialize eder
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let mut offer = self.offer(offer_id).get();
+
+        // require! makrosu ile kritik iş mantığı kontrollerini yapıyoruz
+        // Bu kontroller başarısız olursa işlem geri alınır ve EGLD iade edilir
+        // Aktif olmayan teklif kabul edilemez - bu mantıksal bir gerekliliktir
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
+
+        // Sadece teklifteki alıcı (recipient) kabul edebilir
+        // Bu önemli bir güvenlik kontrolüdür - başkasının teklifini kabul edemezsiniz
+        require!(offer.recipient == caller, ERR_NOT_OFFER_RECIPIENT);
+
+        // Açık artırmalar bid / buy / settleAuction, satış teklifleri acceptPartial ile sonuçlanır
+        require!(self.auction(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_AUCTION);
+        require!(self.sell_offer(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_SELL_OFFER);
+
+        // Taraflardan biri engellenmişse fonlar alıcıya aktarılamaz
+        require!(!self.blocked_addresses().contains(&caller), ERR_RECIPIENT_BLOCKED);
+        require!(!self.blocked_addresses().contains(&offer.creator), ERR_CREATOR_BLOCKED);
+
+        // Takas istendiyse token için yapılandırılmış bir swap yolu olmalıdır
+        // Teslimat çağrısı EGLD gönderdiği için takas ile birlikte kullanılamaz
+        let swap = opt_swap.into_option().map(|swap| swap.into_tuple());
+        if let Some((token_out, _)) = &swap {
+            require!(self.delivery_call(offer_id).is_empty(), ERR_SWAP_WITH_DELIVERY_CALL);
+            require!(self.staking_position(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_STAKED_OFFER);
+            require!(
+                !self.egld_wrapper().is_empty() && self.swap_pairs().contains_key(token_out),
+                ERR_SWAP_ROUTE_NOT_SET
+            );
+        }
+
+        let old_status = offer.status.clone();
+        offer.closed_by = caller;
+
+        // Teklifin durumunu Completed olarak güncelliyoruz ve kilitli EGLD'yi alıcıya gönderiyoruz
+        // Teslimat çağrısı tanımlıysa fonlar alıcı kontrata çağrı ile gönderilir
+        // ve teklif çağrının sonucu gelene kadar PendingDelivery durumunda kalır
+        self.release_to_recipient(&mut offer, swap);
+
+        // Kabul işlemini blockchain'de logluyoruz
+        // Bu sayede frontend uygulamalar ve kullanıcılar işlemi takip edebilir
+        // Event parametreleri: teklif ID'si, taraflar ve teklifin anlık görüntüsü
+        self.accept_offer_event(
+            offer_id,
+            &offer.creator,
+            &offer.recipient,
+            &self.offer_event_data(&offer, Some(old_status))
+        );
+    }
+
+    // Teklifi oluşturanın fonları alıcıya kendisinin göndermesi
+    // Alıcı bir kontrat olduğunda acceptOffer çağıramayabilir, bu durumda fonlar oluşturan tarafından serbest bırakılır
+    // Teslimat çağrısı tanımlıysa acceptOffer'daki gibi PendingDelivery üzerinden ilerler
+    #[endpoint(releaseOffer)]
+    fn release_offer(&self, offer_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let mut offer = self.offer(offer_id).get();
+
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
+        require!(offer.creator == caller, ERR_NOT_OFFER_CREATOR);
+        require!(self.auction(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_AUCTION);
+        require!(self.sell_offer(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_SELL_OFFER);
+        require!(!self.blocked_addresses().contains(&caller), ERR_CREATOR_BLOCKED);
+        require!(!self.blocked_addresses().contains(&offer.recipient), ERR_RECIPIENT_BLOCKED);
+
+        let old_status = offer.status.clone();
+        offer.closed_by = caller;
+
+        self.release_to_recipient(&mut offer, None);
+        self.release_offer_event(
+            offer_id,
+            &offer.creator,
+            &offer.recipient,
+            &self.offer_event_data(&offer, Some(old_status))
+        );
+    }
+
+    // Serbest bırakma koşulu olan bir teklifin koşulunu kontrol eder
+    // Herkes çağırabilir, koşul sağlanıyorsa fonlar alıcıya aktarılır
+    // Koşul view'ı senkron ve salt okunur olarak çağrılır, hedef kontrat durum değiştiremez
+    #[endpoint(tryRelease)]
+    fn try_release(&self, offer_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let mut offer = self.offer(offer_id).get();
+
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
+        require!(!self.release_condition(offer_id).is_empty(), ERR_NO_RELEASE_CONDITION);
+        require!(!self.blocked_addresses().contains(&offer.creator), ERR_CREATOR_BLOCKED);
+        require!(!self.blocked_addresses().contains(&offer.recipient), ERR_RECIPIENT_BLOCKED);
+        require!(self.is_release_condition_met(offer_id), ERR_RELEASE_CONDITION_NOT_MET);
+
+        let old_status = offer.status.clone();
+        offer.closed_by = caller;
+
+        self.release_to_recipient(&mut offer, None);
+        self.conditional_release_event(
+            offer_id,
+            &offer.creator,
+            &offer.recipient,
+            &self.offer_event_data(&offer, Some(old_status))
+        );
+    }
+
+    // Koşulun hedefi escrow ile aynı shard'daki bir kontrat olmalı ve view adı boş olmamalıdır
+    fn require_valid_release_condition(&self, condition: &ReleaseCondition<Self::Api>) {
+        require!(
+            self.is_same_shard_contract(&condition.target) && !condition.view.is_empty(),
+            ERR_INVALID_RELEASE_CONDITION
+        );
+    }
+
+    // Senkron çağrılar sadece aynı shard'daki kontratlara yapılabilir
+    fn is_same_shard_contract(&self, address: &ManagedAddress) -> bool {
+        let own_shard = self.blockchain().get_shard_of_address(&self.blockchain().get_sc_address());
+
+        self.blockchain().is_smart_contract(address) && self.blockchain().get_shard_of_address(address) == own_shard
+    }
+
+    // Koşul view'ını çağırır, sonuç tek bir değer olmalı ve beklenen değerle aynı olmalıdır
+    fn is_release_condition_met(&self, offer_id: u64) -> bool {
+        let condition = self.release_condition(offer_id).get();
+        let result = self
+            .tx()
+            .to(&condition.target)
+            .gas(RELEASE_CONDITION_GAS)
+            .raw_call(condition.view)
+            .arguments_raw(condition.arguments.into())
+            .returns(ReturnsRawResult)
+            .sync_call_readonly();
+
+        result.len() == 1 && *result.get(0) == condition.expected
+    }
+
+    // Fonları alıcıya aktarır
+    // Teslimat çağrısı tanımlıysa fonlar alıcı kontrata çağrı ile gönderilir
+    // ve teklif çağrının sonucu gelene kadar PendingDelivery durumunda kalır
+    // swap verildiyse ödeme EGLD yerine swap ile alınan token olarak gönderilir
+    // Çağıran fonksiyon teklifin aktif olduğunu kontrol etmeli ve closed_by alanını ayarlamalıdır
+    fn release_to_recipient(
+        &self,
+        offer: &mut Offer<Self::Api>,
+        swap: Option<(TokenIdentifier, BigUint)>
+    ) {
+        if !self.usd_amount(offer.offer_id).is_empty() {
+            self.settle_usd_amount(offer);
+        }
+
+        if !self.delivery_call(offer.offer_id).is_empty() {
+            offer.status = OfferStatus::PendingDelivery;
+            self.save_offer(offer);
+            self.start_delivery(offer);
+            return;
+        }
+
+        // mark_offer_completed() teklifi kaydeder ve sayaçları günceller
+        // send().direct_egld() transferi atomiktir - ya tamamen başarılı olur ya da işlem geri alınır
+        self.mark_offer_completed(offer);
+        if !self.staking_position(offer.offer_id).is_empty() {
+            let recipient = offer.recipient.clone();
+            self.close_staking_position(offer, &recipient, true);
+            return;
+        }
+
+        match swap {
+            Some((token_out, amount_out_min)) => self.swap_to_recipient(offer, &token_out, &amount_out_min),
+            None => self.send().direct_egld(&offer.recipient, &offer.amount)
+        }
+    }
+
+    // Teklifi tamamlanmış olarak kaydeder ve sayaçları günceller
+    // Fonların alıcıya gönderilmesi çağıran fonksiyonun sorumluluğundadır
+    fn mark_offer_completed(&self, offer: &mut Offer<Self::Api>) {
+        self.close_completed_offer(offer);
+
+        // Kendine teklifler artık oluşturulamaz, öncesinden kalanlar itibar sayaçlarına işlenmez
+        if offer.creator == offer.recipient {
+            return;
+        }
+        self.update_reputation(&offer.creator, |reputation| reputation.completed_as_creator += 1);
+        self.update_reputation(&offer.recipient, |reputation| reputation.accepted_as_recipient += 1);
+    }
+
+    // Teklifi tamamlanmış olarak kaydeder ve kapanışı sayaçlara işler, itibar sayaçlarına dokunmaz
+    fn close_completed_offer(&self, offer: &mut Offer<Self::Api>) {
+        offer.status = OfferStatus::Completed;
+        offer.closed_timestamp = self.blockchain().get_block_timestamp();
+        self.save_offer(offer);
+
+        self.record_offer_closed(offer);
+    }
+
+    // USD cinsinden teklifler - Ödeme miktarının fiyat oracle'ı ile hesaplanması
+
+    // USD cinsinden tekliflerin fiyatını okumak için kullanılacak oracle'ı ayarlar
+    // Oracle MultiversX price aggregator arayüzünü (latestPriceFeed) sağlamalıdır
+    // Fiyat senkron okunduğu için oracle escrow ile aynı shard'da olmalıdır
+    // max_price_age: kabul edilen en eski fiyatın yaşı (saniye), 1 ile MAX_PRICE_AGE_LIMIT arasında olmalıdır
+    #[endpoint(setPriceOracle)]
+    fn set_price_oracle(&self, oracle: ManagedAddress, max_price_age: u64) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.is_same_shard_contract(&oracle), ERR_INVALID_PRICE_ORACLE);
+        require!(
+            max_price_age > 0 && max_price_age <= MAX_PRICE_AGE_LIMIT,
+            ERR_INVALID_MAX_PRICE_AGE
+        );
+
+        self.price_oracle().set(oracle);
+        self.max_price_age().set(max_price_age);
+    }
+
+    // USD cinsinden teklifin ödeme miktarını güncel fiyatla hesaplar
+    // Kilitli miktarın fazlası oluşturana iade edilir ve teklifin miktarı ödenecek miktara düşürülür
+    // Kilitli miktar yetmiyorsa işlem geri alınır, teklif aktif kalır
+    fn settle_usd_amount(&self, offer: &mut Offer<Self::Api>) {
+        let usd_amount = self.usd_amount(offer.offer_id).get();
+        let (price, decimals) = self.egld_usd_price();
+        let payout = self.usd_to_egld(&usd_amount, &price, decimals);
+        require!(payout <= offer.amount, ERR_INSUFFICIENT_COLLATERAL);
+
+        let refund = &offer.amount - &payout;
+        offer.remaining_amount = payout.clone();
+        offer.amount = payout;
+        if refund > 0u32 {
+            self.value_locked(&EgldOrEsdtTokenIdentifier::egld()).update(|value| *value -= &refund);
+            self.send().direct_egld(&offer.creator, &refund);
+        }
+
+        self.usd_offer_settled_event(offer.offer_id, &UsdOfferSettledEventData { usd_amount, price, refund });
+    }
+
+    // Oracle'dan EGLD/USD fiyatını ve fiyatın ondalık basamak sayısını okur
+    fn egld_usd_price(&self) -> (BigUint, u8) {
+        require!(!self.price_oracle().is_empty(), ERR_PRICE_ORACLE_NOT_SET);
+
+        let (_round_id, _from, _to, timestamp, price, decimals) = self
+            .tx()
+            .to(&self.price_oracle().get())
+            .gas(PRICE_ORACLE_GAS)
+            .raw_call("latestPriceFeed")
+            .argument(&ManagedBuffer::new_from_bytes(PRICE_FEED_BASE))
+            .argument(&ManagedBuffer::new_from_bytes(PRICE_FEED_QUOTE))
+            .original_result::<MultiValue6<u32, ManagedBuffer, ManagedBuffer, u64, BigUint, u8>>()
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .into_tuple();
+
+        // Oracle'ın döndürdüğü zaman damgası taşmaya yol açmamalıdır
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            price > 0u32 && timestamp.saturating_add(self.max_price_age().get()) >= current_timestamp,
+            ERR_INVALID_ORACLE_PRICE
+        );
+
+        (price, decimals)
+    }
+
+    // egld = usd * 10^(18 + fiyat ondalığı) / (fiyat * 10^2)
+    // Alıcının USD miktarının tamamını alması için yukarı yuvarlanır
+    fn usd_to_egld(&self, usd_amount: &BigUint, price: &BigUint, decimals: u8) -> BigUint {
+        let numerator = usd_amount * &BigUint::from(10u32).pow(EGLD_DECIMALS + decimals as u32);
+        let denominator = price * &BigUint::from(10u32).pow(USD_DECIMALS);
+
+        (numerator + &denominator - 1u32) / denominator
+    }
+
+    // Token kısıtlamaları - Kabul edilen tokenlar ve token başına miktar sınırları
+
+    // ESDT'yi teklif, açık artırma ve satış teklifi ödemesi olarak kabul edilen tokenlara ekler
+    // EGLD her zaman kabul edilir, izin listesinde olmayan ESDT ve NFT'ler reddedilir
+    #[endpoint(allowToken)]
+    fn allow_token(&self, token: TokenIdentifier) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.allowed_tokens().insert(token), ERR_TOKEN_ALREADY_ALLOWED);
+    }
+
+    // Token izin listesinden çıkarılır, mevcut teklifler etkilenmez
+    #[endpoint(removeAllowedToken)]
+    fn remove_allowed_token(&self, token: TokenIdentifier) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.allowed_tokens().remove(&token), ERR_TOKEN_NOT_ALLOWED);
+    }
+
+    // Token için kabul edilen en az ve en çok teklif miktarını ayarlar (EGLD dahil)
+    // max_amount 0 ise üst sınır uygulanmaz
+    #[endpoint(setTokenLimits)]
+    fn set_token_limits(&self, token: EgldOrEsdtTokenIdentifier, min_amount: BigUint, max_amount: BigUint) {
+        self.require_caller_has_role(Role::Admin);
+        require!(max_amount == 0u32 || min_amount <= max_amount, ERR_INVALID_TOKEN_LIMITS);
+
+        self.token_limits().insert(token, TokenLimits { min_amount, max_amount });
+    }
+
+    #[endpoint(removeTokenLimits)]
+    fn remove_token_limits(&self, token: EgldOrEsdtTokenIdentifier) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.token_limits().remove(&token).is_some(), ERR_TOKEN_LIMITS_NOT_SET);
+    }
+
+    // Ödemenin izin listesinde olduğunu ve token için ayarlanan sınırlar içinde kaldığını kontrol eder
+    fn require_token_accepted(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if token.is_esdt() {
+            require!(
+                self.allowed_tokens().contains(&token.clone().unwrap_esdt()),
+                ERR_TOKEN_NOT_ALLOWED
+            );
+        }
+
+        if let Some(limits) = self.token_limits().get(token) {
+            require!(*amount >= limits.min_amount, ERR_AMOUNT_BELOW_TOKEN_MIN);
+            require!(
+                limits.max_amount == 0u32 || *amount <= limits.max_amount,
+                ERR_AMOUNT_ABOVE_TOKEN_MAX
+            );
+        }
+    }
+
+    // Takas - Ödemenin alıcının istediği tokena DEX üzerinden çevrilmesi
+
+    // EGLD'yi swap öncesinde WEGLD'ye çeviren wrapper kontratını ayarlar
+    #[endpoint(setEgldWrapper)]
+    fn set_egld_wrapper(&self, wrapper: ManagedAddress) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.is_same_shard_contract(&wrapper), ERR_INVALID_SWAP_CONTRACT);
+
+        self.egld_wrapper().set(wrapper);
+    }
+
+    // WEGLD'yi token_out'a çeviren xExchange pair kontratını ayarlar
+    // Swap senkron yapıldığı için pair escrow ile aynı shard'da olmalıdır
+    #[endpoint(setSwapPair)]
+    fn set_swap_pair(&self, token_out: TokenIdentifier, pair: ManagedAddress) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.is_same_shard_contract(&pair), ERR_INVALID_SWAP_CONTRACT);
+
+        self.swap_pairs().insert(token_out, pair);
+    }
+
+    #[endpoint(removeSwapPair)]
+    fn remove_swap_pair(&self, token_out: TokenIdentifier) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.swap_pairs().remove(&token_out).is_some(), ERR_SWAP_ROUTE_NOT_SET);
+    }
+
+    // Ödemeyi WEGLD'ye çevirir, pair üzerinden token_out'a takas eder ve alıcıya gönderir
+    // Alınan miktar en az çıktı miktarından azsa işlem tamamen geri alınır ve teklif aktif kalır
+    fn swap_to_recipient(&self, offer: &Offer<Self::Api>, token_out: &TokenIdentifier, amount_out_min: &BigUint) {
+        let wrapped = self
+            .tx()
+            .to(&self.egld_wrapper().get())
+            .gas(SWAP_GAS)
+            .raw_call("wrapEgld")
+            .egld(&offer.amount)
+            .returns(ReturnsBackTransfersSingleESDT)
+            .sync_call();
+
+        let output = self
+            .tx()
+            .to(&self.swap_pairs().get(token_out).unwrap())
+            .gas(SWAP_GAS)
+            .raw_call("swapTokensFixedInput")
+            .single_esdt(&wrapped.token_identifier, 0, &wrapped.amount)
+            .argument(token_out)
+            .argument(amount_out_min)
+            .returns(ReturnsBackTransfersSingleESDT)
+            .sync_call();
+
+        // Pair kontratı kaymayı kendisi de kontrol eder, alınan token ve miktar yine de doğrulanır
+        require!(
+            &output.token_identifier == token_out && &output.amount >= amount_out_min,
+            ERR_SWAP_OUTPUT_TOO_LOW
+        );
+
+        self.send().direct_esdt(&offer.recipient, token_out, 0, &output.amount);
+        self.payout_swapped_event(offer.offer_id, token_out, &output.amount);
+    }
+
+    // Likit staking - Uzun süreli tekliflerde kilitli EGLD'nin getiri sağlaması
+
+    // Kilitli EGLD'nin yatırılacağı likit staking kontratını ve getiriden alıcıya düşen payı ayarlar
+    // Kontrat MultiversX liquid staking arayüzünü (addLiquidity, getLsValueForPosition) sağlamalıdır
+    // Çağrılar senkron yapıldığı için kontrat escrow ile aynı shard'da olmalıdır
+    // Pay değişikliği sadece sonradan oluşturulan teklifleri etkiler
+    #[endpoint(setLiquidStaking)]
+    fn set_liquid_staking(&self, liquid_staking: ManagedAddress, recipient_yield_share: u32) {
+        self.require_caller_has_role(Role::Admin);
+        require!(self.is_same_shard_contract(&liquid_staking), ERR_INVALID_LIQUID_STAKING);
+        require!(recipient_yield_share <= MAX_BASIS_POINTS, ERR_INVALID_YIELD_SHARE);
+
+        self.liquid_staking().set(liquid_staking);
+        self.recipient_yield_share().set(recipient_yield_share);
+    }
+
+    // Teklifin kilitli EGLD'sini likit staking kontratına yatırır ve alınan LST'yi kaydeder
+    fn stake_offer_funds(&self, offer: &Offer<Self::Api>) {
+        let liquid_staking = self.liquid_staking().get();
+        let ls_payment = self
+            .tx()
+            .to(&liquid_staking)
+            .gas(LIQUID_STAKING_GAS)
+            .raw_call("addLiquidity")
+            .egld(&offer.amount)
+            .returns(ReturnsBackTransfersSingleESDT)
+            .sync_call();
+
+        self.staking_position(offer.offer_id).set(StakingPosition {
+            liquid_staking,
+            ls_token: ls_payment.token_identifier.clone(),
+            ls_amount: ls_payment.amount.clone(),
+            recipient_yield_share: self.recipient_yield_share().get()
+        });
+
+        self.offer_staked_event(offer.offer_id, &ls_payment.token_identifier, &ls_payment.amount);
+    }
+
+    // Teklifin LST'sini taraflara dağıtır
+    // LST'nin teklif miktarı kadar EGLD'ye karşılık gelen kısmı fonları alan tarafa (principal_receiver) gider
+    // Kalan kısım getiridir ve split_yield true ise oluşturma anındaki orana göre alıcı ile oluşturan arasında paylaştırılır
+    // LST'nin değeri teklif miktarının altına düştüyse tamamı fonları alan tarafa gider
+    fn close_staking_position(&self, offer: &Offer<Self::Api>, principal_receiver: &ManagedAddress, split_yield: bool) {
+        let position = self.staking_position(offer.offer_id).take();
+        let value = self.ls_value(&position.liquid_staking, &position.ls_amount);
+
+        let principal = if value > offer.amount {
+            &position.ls_amount * &offer.amount / &value
+        } else {
+            position.ls_amount.clone()
+        };
+        let accrued_yield = &position.ls_amount - &principal;
+
+        let (recipient_yield, creator_yield) = if split_yield {
+            let recipient_yield = &accrued_yield * position.recipient_yield_share / MAX_BASIS_POINTS;
+            let creator_yield = &accrued_yield - &recipient_yield;
+            (recipient_yield, creator_yield)
+        } else if principal_receiver == &offer.recipient {
+            (accrued_yield, BigUint::zero())
+        } else {
+            (BigUint::zero(), accrued_yield)
+        };
+
+        let mut creator_amount = creator_yield.clone();
+        let mut recipient_amount = recipient_yield.clone();
+        if principal_receiver == &offer.recipient {
+            recipient_amount += &principal;
+        } else {
+            creator_amount += &principal;
+        }
+
+        if creator_amount > 0u32 {
+            self.send().direct_esdt(&offer.creator, &position.ls_token, 0, &creator_amount);
+        }
+        if recipient_amount > 0u32 {
+            self.send().direct_esdt(&offer.recipient, &position.ls_token, 0, &recipient_amount);
+        }
+
+        self.staking_position_closed_event(
+            offer.offer_id,
+            &position.ls_token,
+            &StakingPositionClosedEventData { principal, creator_yield, recipient_yield }
+        );
+    }
+
+    // LST miktarının güncel EGLD karşılığını LST'nin alındığı likit staking kontratından okur
+    fn ls_value(&self, liquid_staking: &ManagedAddress, ls_amount: &BigUint) -> BigUint {
+        self.tx()
+            .to(liquid_staking)
+            .gas(LIQUID_STAKING_GAS)
+            .raw_call("getLsValueForPosition")
+            .argument(ls_amount)
+            .original_result::<BigUint>()
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+    }
+
+    // Açık artırmalar - Sabit bir alıcı yerine varlığın teklif verenlere satılması
+
+    // Gönderilen varlığı (EGLD, ESDT veya NFT) açık artırmaya çıkarır, teklifler ve ödemeler EGLD ile yapılır
+    // English: start_price en düşük tekliftir, end_price 0 olmalıdır
+    // Dutch: fiyat start_price'tan başlar ve duration sonunda end_price'a doğrusal olarak düşer
+    // Sealed: start_price en düşük tekliftir, end_price 0 olmalıdır
+    // teklifler duration boyunca hash olarak verilir ve sonraki reveal_duration boyunca açıklanır
+    // Açık artırma Offer olarak saklanır, bu sayede teklif view'ları ve eventleri aynen kullanılır
+    #[payable("*")]
+    #[endpoint(createAuction)]
+    fn create_auction(
+        &self,
+        kind: AuctionKind,
+        start_price: BigUint,
+        end_price: BigUint,
+        duration: u64,
+        // Sadece Sealed açık artırmalarda verilir
+        opt_reveal_duration: OptionalValue<u64>
+    ) {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0u32, ERR_PAYMENT_ZERO);
+
+        let seller = self.blockchain().get_caller();
+        require!(!self.blocked_addresses().contains(&seller), ERR_CREATOR_BLOCKED);
+        self.require_token_accepted(&payment.token_identifier, &payment.amount);
+
+        let reveal_duration = opt_reveal_duration.into_option().unwrap_or_default();
+        require!(start_price > 0u32 && duration > 0, ERR_INVALID_AUCTION);
+        match kind {
+            AuctionKind::English => require!(end_price == 0u32 && reveal_duration == 0, ERR_INVALID_AUCTION),
+            AuctionKind::Dutch => require!(end_price <= start_price && reveal_duration == 0, ERR_INVALID_AUCTION),
+            AuctionKind::Sealed => require!(end_price == 0u32 && reveal_duration > 0, ERR_INVALID_AUCTION)
+        }
+
+        let new_offer_id = self.last_offer_id().get() + 1;
+        self.last_offer_id().set(new_offer_id);
+
+        let now = self.blockchain().get_block_timestamp();
+        let offer = Offer {
+            version: OFFER_VERSION,
+            offer_id: new_offer_id,
+            creator: seller.clone(),
+            recipient: ManagedAddress::zero(), // İlk teklif verilene kadar boş
+            amount: payment.amount.clone(),
+            remaining_amount: payment.amount.clone(),
+            status: OfferStatus::Active,
+            created_timestamp: now,
+            metadata: OfferMetadata {
+                memo: ManagedBuffer::new(),
+                reference: ManagedBuffer::new(),
+                terms_hash: ManagedBuffer::new()
+            },
+            closed_timestamp: 0,
+            closed_by: ManagedAddress::zero()
+        };
+        self.offer(new_offer_id).set(offer.clone());
+        self.user_offers(&seller).insert(new_offer_id);
+
+        let (reveal_deadline, unrevealed_penalty) = if kind == AuctionKind::Sealed {
+            (now + duration + reveal_duration, self.unrevealed_bid_penalty().get())
+        } else {
+            (0, 0)
+        };
+        self.auction(new_offer_id).set(Auction {
+            kind,
+            token: payment.token_identifier.clone(),
+            nonce: payment.token_nonce,
+            start_price,
+            end_price,
+            deadline: now + duration,
+            reveal_deadline,
+            unrevealed_penalty
+        });
+
+        self.create_offer_event(new_offer_id, &seller, &offer.recipient, &self.offer_event_data(&offer, None));
+
+        self.record_offer_created(&offer);
+        self.update_reputation(&seller, |reputation| reputation.offers_created += 1);
+    }
+
+    // English açık artırmaya teklif verir, teklif edilen EGLD kontratta kilitlenir
+    // Teklif bir öncekinden yüksek olmalıdır, geçilen teklif sahibinin iade bakiyesine eklenir
+    // En yüksek teklifi veren teklifin alıcısı (recipient) olarak kaydedilir
+    #[payable("EGLD")]
+    #[endpoint]
+    fn bid(&self, offer_id: u64) {
+        let bid = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+
+        let (mut offer, auction) = self.require_active_auction(offer_id, AuctionKind::English);
+        require!(offer.creator != caller, ERR_CREATOR_CANNOT_BID);
+        require!(!self.blocked_addresses().contains(&caller), ERR_RECIPIENT_BLOCKED);
+
+        if self.highest_bid(offer_id).is_empty() {
+            require!(bid >= auction.start_price, ERR_BID_TOO_LOW);
+        } else {
+            let previous_bid = self.highest_bid(offer_id).get();
+            require!(bid > previous_bid, ERR_BID_TOO_LOW);
+
+            self.user_incoming_offers(&offer.recipient).remove(&offer_id);
+            self.credit_refund(offer_id, &offer.recipient, &previous_bid);
+        }
+
+        offer.recipient = caller.clone();
+        self.save_offer(&mut offer);
+        self.user_incoming_offers(&caller).insert(offer_id);
+        self.highest_bid(offer_id).set(&bid);
+        self.lock_auction_egld(&bid);
+
+        self.auction_bid_event(offer_id, &caller, &bid);
+    }
+
+    // Dutch açık artırmadaki varlığı güncel fiyattan satın alır
+    // Fiyatın üzerinde gönderilen EGLD iade edilir, ödenen fiyat oluşturana aktarılır
+    #[payable("EGLD")]
+    #[endpoint]
+    fn buy(&self, offer_id: u64) {
+        let payment = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+
+        let (mut offer, auction) = self.require_active_auction(offer_id, AuctionKind::Dutch);
+        require!(offer.creator != caller, ERR_CREATOR_CANNOT_BID);
+        require!(!self.blocked_addresses().contains(&caller), ERR_RECIPIENT_BLOCKED);
+        require!(!self.blocked_addresses().contains(&offer.creator), ERR_CREATOR_BLOCKED);
+
+        let price = self.dutch_auction_price(&offer, &auction);
+        require!(payment >= price, ERR_BID_TOO_LOW);
+
+        offer.recipient = caller.clone();
+        self.user_incoming_offers(&caller).insert(offer_id);
+        self.highest_bid(offer_id).set(&price);
+
+        let change = &payment - &price;
+        if change > 0u32 {
+            self.send().direct_egld(&caller, &change);
+        }
+
+        offer.closed_by = caller;
+        self.complete_auction(&mut offer, &price);
+    }
+
+    // Süresi dolan English veya açıklama süresi dolan Sealed açık artırmayı sonuçlandırır, herkes çağırabilir
+    // Teklif varsa varlık en yüksek teklifi verene, teklif oluşturana aktarılır
+    // Teklif yoksa varlık oluşturana iade edilir ve açık artırma iptal edilmiş sayılır
+    // Sealed açık artırmada açıklanmayan teminatlardan ceza kesilerek oluşturana aktarılır, kalanı iade edilir
+    #[endpoint(settleAuction)]
+    fn settle_auction(&self, offer_id: u64) {
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let mut offer = self.offer(offer_id).get();
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
+
+        require!(!self.auction(offer_id).is_empty(), ERR_WRONG_AUCTION_KIND);
+        let auction = self.auction(offer_id).get();
+        let end = match auction.kind {
+            AuctionKind::English => auction.deadline,
+            AuctionKind::Sealed => auction.reveal_deadline,
+            AuctionKind::Dutch => sc_panic!(ERR_WRONG_AUCTION_KIND)
+        };
+        require!(self.blockchain().get_block_timestamp() >= end, ERR_AUCTION_NOT_ENDED);
+
+        // Engellenmiş taraf varsa açık artırma adminRefundOffer ile iade edilir
+        require!(!self.blocked_addresses().contains(&offer.creator), ERR_CREATOR_BLOCKED);
+        require!(!self.blocked_addresses().contains(&offer.recipient), ERR_RECIPIENT_BLOCKED);
+
+        offer.closed_by = self.blockchain().get_caller();
+        self.settle_unrevealed_bids(&offer, &auction);
+
+        if self.highest_bid(offer_id).is_empty() {
+            let old_status = offer.status.clone();
+            offer.status = OfferStatus::Cancelled;
+            offer.closed_timestamp = self.blockchain().get_block_timestamp();
+            self.save_offer(&mut offer);
+
+            self.send_auction_asset(&offer, &offer.creator);
+            self.cancel_offer_event(
+                offer_id,
+                &offer.creator,
+                &offer.recipient,
+                &self.offer_event_data(&offer, Some(old_status))
+            );
+            self.record_offer_closed(&offer);
+            return;
+        }
+
+        let price = self.highest_bid(offer_id).get();
+        self.unlock_auction_egld(&price);
+        self.complete_auction(&mut offer, &price);
+    }
+
+    // Kapalı teklifli açık artırmaya teklif verir
+    // bid_hash computeBidHash(çağıran, miktar, salt) ile hesaplanır, miktar ve salt açıklamaya kadar gizli kalır
+    // Gönderilen EGLD teminattır, teklifi gizlemek için tekliften fazla gönderilebilir
+    #[payable("EGLD")]
+    #[endpoint(commitBid)]
+    fn commit_bid(&self, offer_id: u64, bid_hash: ManagedByteArray<32>) {
+        let deposit = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+
+        let (offer, auction) = self.require_active_auction(offer_id, AuctionKind::Sealed);
+        require!(offer.creator != caller, ERR_CREATOR_CANNOT_BID);
+        require!(!self.blocked_addresses().contains(&caller), ERR_RECIPIENT_BLOCKED);
+        require!(deposit >= auction.start_price, ERR_BID_TOO_LOW);
+        require!(!self.sealed_bids(offer_id).contains_key(&caller), ERR_BID_ALREADY_COMMITTED);
+        require!(self.sealed_bids(offer_id).len() < MAX_SEALED_BIDS, ERR_TOO_MANY_SEALED_BIDS);
+
+        self.sealed_bids(offer_id).insert(caller.clone(), SealedBid { bid_hash, deposit: deposit.clone() });
+        self.lock_auction_egld(&deposit);
+        self.sealed_bid_committed_event(offer_id, &caller, &deposit);
+    }
+
+    // Kapalı teklifi açıklama süresinde açıklar
+    // Teklif şu ana kadarki en yüksek teklifse öne geçer ve teminatın fazlası iade bakiyesine eklenir
+    // Geçilen teklif ve öne geçemeyen teklifin teminatı da sahibinin iade bakiyesine eklenir
+    #[endpoint(revealBid)]
+    fn reveal_bid(&self, offer_id: u64, amount: BigUint, salt: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let mut offer = self.offer(offer_id).get();
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
+
+        require!(!self.auction(offer_id).is_empty(), ERR_WRONG_AUCTION_KIND);
+        let auction = self.auction(offer_id).get();
+        require!(auction.kind == AuctionKind::Sealed, ERR_WRONG_AUCTION_KIND);
+
+        let now = self.blockchain().get_block_timestamp();
+        require!(now >= auction.deadline, ERR_REVEAL_NOT_OPEN);
+        require!(now < auction.reveal_deadline, ERR_AUCTION_ENDED);
+
+        let sealed_bid = self.sealed_bids(offer_id).get(&caller);
+        require!(sealed_bid.is_some(), ERR_NO_SEALED_BID);
+        let sealed_bid = sealed_bid.unwrap();
+        require!(
+            self.compute_bid_hash(caller.clone(), amount.clone(), salt) == sealed_bid.bid_hash,
+            ERR_BID_HASH_MISMATCH
+        );
+        require!(amount <= sealed_bid.deposit, ERR_BID_EXCEEDS_DEPOSIT);
+        require!(amount >= auction.start_price, ERR_BID_TOO_LOW);
+
+        self.sealed_bids(offer_id).remove(&caller);
+        self.sealed_bid_revealed_event(offer_id, &caller, &amount);
+
+        // Eşit tekliflerde önce açıklanan önde kalır
+        let highest_bid = self.highest_bid(offer_id).get();
+        if !self.highest_bid(offer_id).is_empty() && amount <= highest_bid {
+            self.credit_refund(offer_id, &caller, &sealed_bid.deposit);
+            return;
+        }
+
+        if !self.highest_bid(offer_id).is_empty() {
+            self.user_incoming_offers(&offer.recipient).remove(&offer_id);
+            self.credit_refund(offer_id, &offer.recipient, &highest_bid);
+        }
+
+        offer.recipient = caller.clone();
+        self.save_offer(&mut offer);
+        self.user_incoming_offers(&caller).insert(offer_id);
+        self.highest_bid(offer_id).set(&amount);
+
+        let change = &sealed_bid.deposit - &amount;
+        if change > 0u32 {
+            self.credit_refund(offer_id, &caller, &change);
+        }
+    }
+
+    // Kapalı teklifli açık artırmada açıklanmayan teminatları işler
+    // Teminattan oluşturma anındaki orana göre ceza kesilir ve oluşturana aktarılır, kalanı teklif verenin iade bakiyesine eklenir
+    fn settle_unrevealed_bids(&self, offer: &Offer<Self::Api>, auction: &Auction<Self::Api>) {
+        let mut total_penalty = BigUint::zero();
+        for (bidder, sealed_bid) in self.sealed_bids(offer.offer_id).iter() {
+            let penalty = &sealed_bid.deposit * auction.unrevealed_penalty / MAX_BASIS_POINTS;
+            let refund = &sealed_bid.deposit - &penalty;
+            if refund > 0u32 {
+                self.credit_refund(offer.offer_id, &bidder, &refund);
+            }
+
+            self.unrevealed_bid_penalized_event(offer.offer_id, &bidder, &penalty);
+            total_penalty += penalty;
+        }
+        self.sealed_bids(offer.offer_id).clear();
+
+        if total_penalty > 0u32 {
+            self.unlock_auction_egld(&total_penalty);
+            self.send().direct_egld(&offer.creator, &total_penalty);
+        }
+    }
+
+    // İade edilecek EGLD'yi teklif verenin iade bakiyesine ekler
+    // Gönderim teklif verene bırakılır, böylece EGLD kabul etmeyen bir teklif veren açık artırmayı kilitleyemez
+    // EGLD çekilene kadar kontratta kalır ve kilitli değerde sayılmaya devam eder
+    fn credit_refund(&self, offer_id: u64, bidder: &ManagedAddress, amount: &BigUint) {
+        self.pending_refunds(bidder).update(|balance| *balance += amount);
+        self.refund_credited_event(offer_id, bidder, amount);
+    }
+
+    // Çağıranın açık artırmalardan biriken iade bakiyesini gönderir
+    // Engellenen adresler diğer ödemelerde olduğu gibi engel kaldırılana kadar çekemez
+    #[endpoint(withdrawRefund)]
+    fn withdraw_refund(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.blocked_addresses().contains(&caller), ERR_RECIPIENT_BLOCKED);
+
+        let amount = self.pending_refunds(&caller).take();
+        require!(amount > 0u32, ERR_NO_PENDING_REFUND);
+
+        self.unlock_auction_egld(&amount);
+        self.send().direct_egld(&caller, &amount);
+        self.refund_withdrawn_event(&caller, &amount);
+    }
+
+    // Yeni kapalı teklifli açık artırmalarda açıklanmayan teminattan kesilecek payı ayarlar (baz puan)
+    // Değişiklik sadece sonradan oluşturulan açık artırmaları etkiler
+    #[endpoint(setUnrevealedBidPenalty)]
+    fn set_unrevealed_bid_penalty(&self, penalty: u32) {
+        self.require_caller_has_role(Role::Admin);
+        require!(penalty <= MAX_BASIS_POINTS, ERR_INVALID_PENALTY);
+
+        self.unrevealed_bid_penalty().set(penalty);
+    }
+
+    // Açık artırmayı kazanan ile tamamlar: varlık kazanana, fiyat oluşturana gönderilir
+    fn complete_auction(&self, offer: &mut Offer<Self::Api>, price: &BigUint) {
+        let old_status = offer.status.clone();
+        self.mark_offer_completed(offer);
+
+        self.send_auction_asset(offer, &offer.recipient);
+        self.send().direct_egld(&offer.creator, price);
+
+        self.accept_offer_event(
+            offer.offer_id,
+            &offer.creator,
+            &offer.recipient,
+            &self.offer_event_data(offer, Some(old_status))
+        );
+        self.auction_settled_event(offer.offer_id, &offer.recipient, price);
+    }
+
+    // Teklifin aktif ve verilen türde bir açık artırma olduğunu, süresinin dolmadığını kontrol eder
+    fn require_active_auction(&self, offer_id: u64, kind: AuctionKind) -> (Offer<Self::Api>, Auction<Self::Api>) {
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let offer = self.offer(offer_id).get();
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
+
+        require!(!self.auction(offer_id).is_empty(), ERR_WRONG_AUCTION_KIND);
+        let auction = self.auction(offer_id).get();
+        require!(auction.kind == kind, ERR_WRONG_AUCTION_KIND);
+        require!(self.blockchain().get_block_timestamp() < auction.deadline, ERR_AUCTION_ENDED);
+
+        (offer, auction)
+    }
+
+    // Dutch açık artırmanın güncel fiyatı
+    // Fiyat oluşturma anından süre sonuna kadar start_price'tan end_price'a doğrusal olarak düşer
+    fn dutch_auction_price(&self, offer: &Offer<Self::Api>, auction: &Auction<Self::Api>) -> BigUint {
+        let now = self.blockchain().get_block_timestamp();
+        if now >= auction.deadline {
+            return auction.end_price.clone();
+        }
+
+        let elapsed = now - offer.created_timestamp;
+        let duration = auction.deadline - offer.created_timestamp;
+        let decay = (&auction.start_price - &auction.end_price) * elapsed / duration;
+        &auction.start_price - &decay
+    }
+
+    // Satılan varlığı verilen adrese gönderir
+    fn send_auction_asset(&self, offer: &Offer<Self::Api>, to: &ManagedAddress) {
+        let auction = self.auction(offer.offer_id).get();
+        self.send().direct(to, &auction.token, auction.nonce, &offer.amount);
+    }
+
+    // Kısmi satış - Kilitli tokenların birden fazla alıcıya parça parça satılması
+
+    // Gönderilen tokenları toplam price (EGLD) fiyatından herkese açık satışa çıkarır
+    // Alıcılar acceptPartial ile en az min_fill kadar alım yapabilir, ödeme fiyatla orantılıdır
+    // Kalan miktar sıfırlandığında teklif kendiliğinden tamamlanır, oluşturan kalan miktarı cancelOffer ile geri alabilir
+    #[payable("*")]
+    #[endpoint(createSellOffer)]
+    fn create_sell_offer(&self, price: BigUint, min_fill: BigUint) {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0u32, ERR_PAYMENT_ZERO);
+
+        let seller = self.blockchain().get_caller();
+        require!(!self.blocked_addresses().contains(&seller), ERR_CREATOR_BLOCKED);
+        self.require_token_accepted(&payment.token_identifier, &payment.amount);
+        require!(
+            price > 0u32 && min_fill > 0u32 && min_fill <= payment.amount,
+            ERR_INVALID_SELL_OFFER
+        );
+
+        let new_offer_id = self.last_offer_id().get() + 1;
+        self.last_offer_id().set(new_offer_id);
+
+        let offer = Offer {
+            version: OFFER_VERSION,
+            offer_id: new_offer_id,
+            creator: seller.clone(),
+            recipient: ManagedAddress::zero(), // İlk alım yapılana kadar boş
+            amount: payment.amount.clone(),
+            remaining_amount: payment.amount.clone(),
+            status: OfferStatus::Active,
+            created_timestamp: self.blockchain().get_block_timestamp(),
+            metadata: OfferMetadata {
+                memo: ManagedBuffer::new(),
+                reference: ManagedBuffer::new(),
+                terms_hash: ManagedBuffer::new()
+            },
+            closed_timestamp: 0,
+            closed_by: ManagedAddress::zero()
+        };
+        self.offer(new_offer_id).set(offer.clone());
+        self.user_offers(&seller).insert(new_offer_id);
+
+        self.sell_offer(new_offer_id).set(SellOffer {
+            token: payment.token_identifier.clone(),
+            nonce: payment.token_nonce,
+            price,
+            min_fill
+        });
+
+        self.create_offer_event(new_offer_id, &seller, &offer.recipient, &self.offer_event_data(&offer, None));
+
+        self.record_offer_created(&offer);
+        self.update_reputation(&seller, |reputation| reputation.offers_created += 1);
+    }
+
+    // Açık satış teklifinden amount kadar token satın alır
+    // Ödenecek tutar price * amount / toplam miktar olarak hesaplanır ve yukarı yuvarlanır, fazlası iade edilir
+    // Tokenlar alıcıya, ödeme oluşturana hemen aktarılır
+    #[payable("EGLD")]
+    #[endpoint(acceptPartial)]
+    fn accept_partial(&self, offer_id: u64, amount: BigUint) {
+        let payment = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let mut offer = self.offer(offer_id).get();
+        require!(offer.status == OfferStatus::Active, ERR_OFFER_NOT_ACTIVE);
+
+        require!(!self.sell_offer(offer_id).is_empty(), ERR_NOT_SELL_OFFER);
+        let sell_offer = self.sell_offer(offer_id).get();
+
+        require!(offer.creator != caller, ERR_CREATOR_CANNOT_FILL);
+        require!(!self.blocked_addresses().contains(&caller), ERR_RECIPIENT_BLOCKED);
+        require!(!self.blocked_addresses().contains(&offer.creator), ERR_CREATOR_BLOCKED);
+
+        require!(amount > 0u32 && amount <= offer.remaining_amount, ERR_INVALID_FILL_AMOUNT);
+        require!(
+            amount >= sell_offer.min_fill || amount == offer.remaining_amount,
+            ERR_FILL_TOO_SMALL
+        );
+
+        let price = self.fill_price(&offer, &sell_offer, &amount);
+        require!(payment >= price, ERR_FILL_PAYMENT_TOO_LOW);
+
+        offer.remaining_amount -= &amount;
+        offer.recipient = caller.clone();
+        self.user_incoming_offers(&caller).insert(offer_id);
+        // Alıcı itibarı teklif başına bir kez artar, küçük parçalı alımlarla şişirilemez
+        let first_fill = self.sell_offer_buyers(offer_id).insert(caller.clone());
+
+        // Satılan miktar kilitli değerden düşülür ve işlem hacmine eklenir
+        let token = sell_offer.token.clone();
+        self.value_locked(&token).update(|value| *value -= &amount);
+        self.volume(&token).update(|value| *value += &amount);
+
+        self.send().direct(&caller, &sell_offer.token, sell_offer.nonce, &amount);
+        self.send().direct_egld(&offer.creator, &price);
+        let change = &payment - &price;
+        if change > 0u32 {
+            self.send().direct_egld(&caller, &change);
+        }
+
+        self.partial_fill_event(
+            offer_id,
+            &caller,
+            &PartialFillEventData { amount, price, remaining_amount: offer.remaining_amount.clone() }
+        );
+
+        if first_fill {
+            self.update_reputation(&caller, |reputation| reputation.accepted_as_recipient += 1);
+        }
+
+        // Son alım teklifi tamamlar ve oluşturanın sayacını artırır
+        if offer.remaining_amount == 0u32 {
+            let old_status = offer.status.clone();
+            offer.closed_by = caller;
+            self.close_completed_offer(&mut offer);
+            self.update_reputation(&offer.creator, |reputation| reputation.completed_as_creator += 1);
+
+            self.accept_offer_event(
+                offer_id,
+                &offer.creator,
+                &offer.recipient,
+                &self.offer_event_data(&offer, Some(old_status))
+            );
+        } else {
+            self.save_offer(&mut offer);
+        }
+    }
+
+    // amount kadar alımın fiyatı: price * amount / toplam miktar
+    // Parçalı alımların toplamı price'ın altında kalmasın diye yukarı yuvarlanır
+    fn fill_price(&self, offer: &Offer<Self::Api>, sell_offer: &SellOffer<Self::Api>, amount: &BigUint) -> BigUint {
+        (&sell_offer.price * amount + &offer.amount - 1u32) / &offer.amount
+    }
+
+    // Satış teklifinin kalan miktarını verilen adrese gönderir
+    fn send_sell_offer_remainder(&self, offer: &Offer<Self::Api>, to: &ManagedAddress) {
+        let sell_offer = self.sell_offer(offer.offer_id).get();
+        self.send().direct(to, &sell_offer.token, sell_offer.nonce, &offer.remaining_amount);
+    }
+
+    // Teslimat - Alıcı kontrata fonların endpoint çağrısı ile gönderilmesi
+
+    // Teklif için teslimat çağrısını tanımlar
+    // Sadece oluşturan çağırabilir ve alıcı bir kontrat olmalıdır
+    // Aktif tekliflerde veya başarısız teslimattan sonra (PendingDelivery) çağrıyı düzeltmek için kullanılabilir
+    #[endpoint(setDeliveryCall)]
+    fn set_delivery_call(
+        &self,
+        offer_id: u64,
+        gas_limit: u64,
+        endpoint: ManagedBuffer,
+        arguments: MultiValueEncoded<ManagedBuffer>
+    ) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let offer = self.offer(offer_id).get();
+
+        require!(offer.creator == caller, ERR_NOT_OFFER_CREATOR);
+        require!(
+            offer.status == OfferStatus::Active || offer.status == OfferStatus::PendingDelivery,
+            ERR_OFFER_NOT_ACTIVE
+        );
+        require!(!self.delivery_in_progress(offer_id).get(), ERR_DELIVERY_IN_PROGRESS);
+        require!(self.blockchain().is_smart_contract(&offer.recipient), ERR_RECIPIENT_NOT_CONTRACT);
+        require!(!endpoint.is_empty(), ERR_DELIVERY_ENDPOINT_EMPTY);
+        // Teslimat çağrısı EGLD gönderdiği için LST tutan tekliflerde kullanılamaz
+        require!(self.staking_position(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_STAKED_OFFER);
+        require!(self.auction(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_AUCTION);
+        require!(self.sell_offer(offer_id).is_empty(), ERR_NOT_SUPPORTED_FOR_SELL_OFFER);
+
+        self.delivery_call(offer_id).set(DeliveryCall {
+            endpoint,
+            arguments: arguments.to_vec(),
+            gas_limit
+        });
+    }
+
+    // Başarısız olmuş bir teslimatı tekrar dener
+    // Oluşturan veya alıcı çağırabilir, önceki çağrının sonucu gelmiş olmalıdır
+    #[endpoint(retryDelivery)]
+    fn retry_delivery(&self, offer_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let offer = self.offer(offer_id).get();
+
+        require!(offer.status == OfferStatus::PendingDelivery, ERR_OFFER_NOT_PENDING_DELIVERY);
+        require!(caller == offer.creator || caller == offer.recipient, ERR_NOT_OFFER_PARTY);
+        require!(!self.delivery_in_progress(offer_id).get(), ERR_DELIVERY_IN_PROGRESS);
+        require!(!self.blocked_addresses().contains(&offer.creator), ERR_CREATOR_BLOCKED);
+        require!(!self.blocked_addresses().contains(&offer.recipient), ERR_RECIPIENT_BLOCKED);
+
+        self.start_delivery(&offer);
+    }
+
+    // Fonları teslimat çağrısı ile alıcı kontrata gönderir
+    // Çağrı asenkron (promise) olarak yapılır, sonucu delivery_callback ile işlenir
+    // Çağrı başarısız olursa fonlar kontrata geri döner ve teklif PendingDelivery durumunda kalır
+    fn start_delivery(&self, offer: &Offer<Self::Api>) {
+        let delivery = self.delivery_call(offer.offer_id).get();
+        self.delivery_in_progress(offer.offer_id).set(true);
+
+        self.tx()
+            .to(&offer.recipient)
+            .raw_call(delivery.endpoint)
+            .arguments_raw(delivery.arguments.into())
+            .egld(&offer.amount)
+            .gas(delivery.gas_limit)
+            .callback(self.callbacks().delivery_callback(offer.offer_id))
+            .gas_for_callback(DELIVERY_CALLBACK_GAS)
+            .register_promise();
+    }
+
+    // Teslimat çağrısının sonucu
+    // Başarılıysa teklif tamamlanır, değilse PendingDelivery durumunda kalır ve tekrar denenebilir
+    #[promises_callback]
+    fn delivery_callback(
+        &self,
+        offer_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>
+    ) {
+        self.delivery_in_progress(offer_id).clear();
+
+        let mut offer = self.offer(offer_id).get();
+        let old_status = offer.status.clone();
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                self.mark_offer_completed(&mut offer);
+                self.delivery_completed_event(
+                    offer_id,
+                    &offer.creator,
+                    &offer.recipient,
+                    &self.offer_event_data(&offer, Some(old_status))
+                );
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                self.delivery_failed_event(
+                    offer_id,
+                    &offer.creator,
+                    &offer.recipient,
+                    &self.offer_event_data(&offer, Some(old_status))
+                );
+            }
+        }
+    }
+
+    // Yaşam döngüsü eventlerinin data alanını teklifin güncel halinden oluşturur
+    fn offer_event_data(
+        &self,
+        offer: &Offer<Self::Api>,
+        old_status: Option<OfferStatus>
+    ) -> OfferEventData<Self::Api> {
+        OfferEventData {
+            payment: self.offer_payment(offer),
+            old_status,
+            new_status: offer.status.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+            metadata: offer.metadata.clone()
+        }
+    }
+
+    // Teklifte kilitli varlık
+    // Açık artırmalarda satılan varlık, satış tekliflerinde satılan token, diğer tekliflerde EGLD
+    // Miktar kilitli kalan miktardır, tamamlanan tekliflerde ise toplam miktar
+    fn offer_payment(&self, offer: &Offer<Self::Api>) -> EgldOrEsdtTokenPayment<Self::Api> {
+        let amount = if offer.status == OfferStatus::Completed {
+            offer.amount.clone()
+        } else {
+            offer.remaining_amount.clone()
+        };
+
+        if !self.auction(offer.offer_id).is_empty() {
+            let auction = self.auction(offer.offer_id).get();
+            return EgldOrEsdtTokenPayment::new(auction.token, auction.nonce, amount);
+        }
+        if !self.sell_offer(offer.offer_id).is_empty() {
+            let sell_offer = self.sell_offer(offer.offer_id).get();
+            return EgldOrEsdtTokenPayment::new(sell_offer.token, sell_offer.nonce, amount);
+        }
+
+        EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, amount)
+    }
+
+    // İstatistikler - Sayaçlar her işlemde artımlı olarak güncellenir
+
+    // Adresin işlem geçmişi sayaçlarını günceller
+    // Storage'da henüz kayıt yoksa sıfır sayaçlarla başlanır
+    fn update_reputation(&self, user: &ManagedAddress, update: impl FnOnce(&mut UserReputation)) {
+        let mut reputation = self.get_user_reputation(user.clone());
+        update(&mut reputation);
+        self.user_reputation(user).set(reputation);
+    }
+
+    // Yeni oluşturulan teklifi sayaçlara ekler
+    fn record_offer_created(&self, offer: &Offer<Self::Api>) {
+        let token = self.offer_payment(offer).token_identifier;
+
+        self.total_offers_created().update(|count| *count += 1);
+        self.active_offer_count().update(|count| *count += 1);
+        self.stats_tokens().insert(token.clone());
+        self.value_locked(&token).update(|value| *value += &offer.amount);
+    }
+
+    // Sonuçlanan teklifi son durumuna göre sayaçlara işler
+    // İstatistikler eklenmeden önce oluşturulmuş teklifler sayılmadığı için atlanır
+    // Satış tekliflerinin satılan kısmı her alımda işlendiği için sadece kalan miktar kullanılır
+    fn record_offer_closed(&self, offer: &Offer<Self::Api>) {
+        if offer.offer_id < self.stats_first_offer_id().get() {
+            return;
+        }
+
+        let token = self.offer_payment(offer).token_identifier;
+
+        self.active_offer_count().update(|count| *count -= 1);
+        self.value_locked(&token).update(|value| *value -= &offer.remaining_amount);
+        match offer.status {
+            OfferStatus::Completed => {
+                self.completed_offer_count().update(|count| *count += 1);
+                self.volume(&token).update(|value| *value += &offer.remaining_amount);
+            },
+            OfferStatus::Cancelled => {
+                self.cancelled_offer_count().update(|count| *count += 1);
+            },
+            OfferStatus::Active | OfferStatus::PendingDelivery => {}
+        }
+    }
+
+    // Açık artırma teklifleri ve kapalı teklif teminatları için kontratta tutulan EGLD'yi kilitli değere ekler
+    fn lock_auction_egld(&self, amount: &BigUint) {
+        let token = EgldOrEsdtTokenIdentifier::egld();
+
+        self.stats_tokens().insert(token.clone());
+        self.value_locked(&token).update(|value| *value += amount);
+    }
+
+    // İade edilen veya oluşturana aktarılan açık artırma EGLD'sini kilitli değerden düşer
+    fn unlock_auction_egld(&self, amount: &BigUint) {
+        self.value_locked(&EgldOrEsdtTokenIdentifier::egld()).update(|value| *value -= amount);
+    }
+
+    // Storage migration - Eski kodlamalı tekliflerin güncel kodlamaya dönüştürülmesi
+
+    // from ID'sinden başlayarak en fazla count adet teklifi güncel kodlamada yeniden kaydeder
+    // Sadece upgrade öncesi oluşturulan teklifler dönüştürülür, diğerleri atlanır
+    // Dönüştürülen teklif sayısını döndürür
+    #[endpoint(migrateOffers)]
+    fn migrate_offers(&self, from: u64, count: u64) -> u64 {
+        self.require_caller_has_role(Role::Admin);
+        require!(count <= MAX_MIGRATION_BATCH, ERR_MIGRATION_BATCH_TOO_LARGE);
+
+        let legacy_offer_count = self.legacy_offer_count().get();
+        let mut migrated = 0u64;
+        for offer_id in from.max(1)..from.saturating_add(count) {
+            if offer_id > legacy_offer_count {
+                break;
+            }
+
+            // Arşivlenmiş teklifler storage'da yer almaz, atlanır
+            if self.offer(offer_id).is_empty() {
+                continue;
+            }
+
+            // get() eski kaydı okurken storage'daki versiyonu ile döndürür
+            let mut offer = self.offer(offer_id).get();
+            if offer.version < OFFER_VERSION {
+                self.save_offer(&mut offer);
+                migrated += 1;
+            }
+        }
+
+        migrated
+    }
+
+    // Teklifi storage'a güncel kodlamada kaydeder
+    // Versiyonsuz (ilk sürüm) bir teklif ilk kez yazılırken bekleyen migration sayısı azaltılır
+    // Teklif güncelleyen tüm endpoint'ler bu fonksiyonu kullanmalıdır
+    fn save_offer(&self, offer: &mut Offer<Self::Api>) {
+        if offer.version == 1 {
+            self.legacy_offers_remaining().update(|remaining| *remaining -= 1);
+        }
+        offer.version = OFFER_VERSION;
+
+        self.offer(offer.offer_id).set(&*offer);
+    }
+
+    // Arşivleme - Sonuçlanmış tekliflerin storage'dan temizlenmesi
+
+    // Sonuçlanmış (kabul/iptal edilmiş) teklifleri arşivler
+    // Herkes çağırabilir, teklifin kapanışından sonra saklama süresi geçmiş olmalıdır
+    // Tam kayıt ve kullanıcı listelerindeki ID'ler silinir, sadece kısa bir özet saklanır
+    // Harici referans eşlemesi silinmez, böylece aynı referans tekrar kullanılamaz
+    #[endpoint(archiveOffers)]
+    fn archive_offers(&self, offer_ids: MultiValueEncoded<u64>) {
+        require!(offer_ids.len() <= MAX_ARCHIVE_BATCH, ERR_ARCHIVE_BATCH_TOO_LARGE);
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let retention_period = self.archive_retention_period().get();
+        for offer_id in offer_ids {
+            require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+            let offer = self.offer(offer_id).get();
+
+            require!(offer.status.is_closed(), ERR_OFFER_NOT_FINISHED);
+            require!(
+                offer.closed_timestamp + retention_period <= current_timestamp,
+                ERR_RETENTION_PERIOD_NOT_ELAPSED
+            );
+
+            self.user_offers(&offer.creator).remove(&offer_id);
+            self.user_incoming_offers(&offer.recipient).remove(&offer_id);
+            // Satış tekliflerinde recipient sadece son alıcıdır, önceki alıcıların listeleri de temizlenir
+            for buyer in self.sell_offer_buyers(offer_id).iter() {
+                self.user_incoming_offers(&buyer).remove(&offer_id);
+            }
+
+            // Arşivlenen versiyonsuz teklif artık migration beklemez
+            if offer.version == 1 {
+                self.legacy_offers_remaining().update(|remaining| *remaining -= 1);
+            }
+
+            self.archived_offer(offer_id).set(OfferSummary {
+                offer_id,
+                status: offer.status,
+                amount: offer.amount,
+                closed_timestamp: offer.closed_timestamp
+            });
+            self.clear_offer_storage(offer_id);
+
+            self.archive_offer_event(offer_id);
+        }
+    }
+
+    // Teklifin kaydını ve teklif ID'sine bağlı tüm ek kayıtları siler
+    // Teklif başına yeni bir storage mapper eklendiğinde buraya da eklenmelidir
+    fn clear_offer_storage(&self, offer_id: u64) {
+        self.offer(offer_id).clear();
+        self.delivery_call(offer_id).clear();
+        self.delivery_in_progress(offer_id).clear();
+        self.release_condition(offer_id).clear();
+        self.usd_amount(offer_id).clear();
+        self.staking_position(offer_id).clear();
+        self.auction(offer_id).clear();
+        self.highest_bid(offer_id).clear();
+        self.sealed_bids(offer_id).clear();
+        self.sell_offer(offer_id).clear();
+        self.sell_offer_buyers(offer_id).clear();
+    }
+
+    // Arşivleme için beklenecek saklama süresini ayarlar (saniye)
+    #[endpoint(setArchiveRetentionPeriod)]
+    fn set_archive_retention_period(&self, retention_period: u64) {
+        self.require_caller_has_role(Role::Admin);
+
+        self.archive_retention_period().set(retention_period);
+    }
+
+    // Rol yönetimi - Owner dışındaki yetkili adresler
+
+    // Bir adrese rol verir
+    // Sadece owner veya Admin rolündeki adresler çağırabilir
+    // Admin rolü doğrudan verilmez, önce öneri yapılır ve aday acceptAdminRole ile kabul eder
+    // Bu sayede yanlış yazılmış bir adrese Admin yetkisi verilmesi engellenir
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_caller_has_role(Role::Admin);
+        require!(!self.role_holders(role).contains(&address), ERR_ADDRESS_ALREADY_HAS_ROLE);
+
+        if role == Role::Admin {
+            require!(self.pending_admins().insert(address.clone()), ERR_ADMIN_ROLE_ALREADY_PROPOSED);
+            self.admin_role_proposed_event(&address);
+            return;
+        }
+
+        self.role_holders(role).insert(address.clone());
+        self.role_granted_event(role, &address);
+    }
+
+    // Bir adresten rolü geri alır
+    // Admin rolü için bekleyen bir öneri varsa öneri de bu endpoint ile geri çekilir
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_caller_has_role(Role::Admin);
+
+        if role == Role::Admin && self.pending_admins().remove(&address) {
+            self.admin_role_proposal_cancelled_event(&address);
+            return;
+        }
+
+        require!(self.role_holders(role).remove(&address), ERR_ADDRESS_DOES_NOT_HAVE_ROLE);
+        self.role_revoked_event(role, &address);
+    }
+
+    // Önerilen Admin rolünü kabul eder (ikinci adım)
+    // Sadece öneri yapılan adres kendisi çağırabilir
+    #[endpoint(acceptAdminRole)]
+    fn accept_admin_role(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.pending_admins().remove(&caller), ERR_NO_PENDING_ADMIN_ROLE);
 
-        // İşlem başarılı - Ok(()) ile başarı durumunu dönüyoruz
-        // () unit type, değer döndürmediğimizi ama işlemin başarılı olduğunu belirtir
-        multiversx_sc::types::SCResult::Ok(())
+        self.role_holders(Role::Admin).insert(caller.clone());
+        self.role_granted_event(Role::Admin, &caller);
     }
 
-    // Teklifi kabul etme fonksiyonu - Bu endpoint sayesinde alıcı kendisine gelen teklifi kabul edebilir
-    // #[endpoint(acceptOffer)] attribute'u bu fonksiyonun blockchain üzerinden çağrılabilir olduğunu belirtir
-    // acceptOffer ismi, fonksiyonun blockchain üzerinden çağrılırken kullanılacak public ismidir
-    #[endpoint(acceptOffer)]
-    fn accept_offer(
-        // &self parametresi, Rust'ta nesne yönelimli programlamada instance metodlarını belirtir
-        // Bu sayede kontrat storage'ına güvenli erişim sağlanır
-        &self,
-        // offer_id: Kabul edilecek teklifin benzersiz kimlik numarası
-        // u64 tipi kullanılır çünkü negatif ID olamaz ve 64-bit yeterli büyüklüktedir
-        offer_id: u64
-    ) -> SCResult<()> { // SCResult dönüş tipi, akıllı kontrat işlemlerinde hata yönetimi için kullanılır
-        
-        // blockchain().get_caller() fonksiyonu çağrıyı yapan adresi güvenli şekilde alır
-        // Bu kritik bir güvenlik kontrolüdür - her zaman gerçek çağıranı doğru tespit etmeliyiz
-        // caller değişkeni immutable olarak tanımlanır çünkü değiştirilmemesi gerekir
+    // Çağıranın owner olduğunu veya istenen role sahip olduğunu kontrol eder
+    // Yetkili tüm endpoint'ler bu kontrol ile korunur
+    fn require_caller_has_role(&self, role: Role) {
         let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address()
+                || self.role_holders(role).contains(&caller),
+            ERR_MISSING_ROLE
+        );
+    }
 
-        // offer() storage mapper'ı ile blockchain'den teklif bilgilerini okuyoruz
-        // mut keyword'ü değişkeni değiştirilebilir yapar çünkü sonra status'ü değiştireceğiz
-        // get() fonksiyonu storage'dan veriyi okur ve deserialize eder
-        let mut offer = self.offer(offer_id).get();
+    // Compliance - Engellenen adres listesinin yönetimi
 
-        // require! makrosu ile kritik iş mantığı kontrollerini yapıyoruz
-        // Bu kontroller başarısız olursa işlem geri alınır ve EGLD iade edilir
-        // Aktif olmayan teklif kabul edilemez - bu mantıksal bir gerekliliktir
-        require!(offer.status == OfferStatus::Active, "Offer not active");
+    // Bir adresi engellenen adresler listesine ekler
+    // Owner veya ComplianceOfficer rolündeki adresler çağırabilir
+    // Engellenen adres teklif oluşturamaz, teklif alamaz ve mevcut tekliflerini kendisi iptal edemez
+    #[endpoint(blockAddress)]
+    fn block_address(&self, address: ManagedAddress) {
+        self.require_caller_has_role(Role::ComplianceOfficer);
 
-        // Sadece teklifteki alıcı (recipient) kabul edebilir
-        // Bu önemli bir güvenlik kontrolüdür - başkasının teklifini kabul edemezsiniz
-        require!(offer.recipient == caller, "Not offer recipient");
+        // insert() adres zaten listedeyse false döner
+        require!(self.blocked_addresses().insert(address.clone()), ERR_ADDRESS_ALREADY_BLOCKED);
 
-        // Teklifin durumunu Completed olarak güncelliyoruz
-        // Enum kullanımı type-safety sağlar, geçersiz durumlar oluşamaz
-        // Bu güncelleme teklifin tamamlandığını belirtir
-        offer.status = OfferStatus::Completed;
+        self.address_blocked_event(&address);
+    }
 
-        // Güncellenmiş teklifi blockchain'e kaydediyoruz
-        // set() fonksiyonu storage'ı güvenli şekilde günceller
-        // Bu kritik bir adımdır - durumu kalıcı olarak değiştirir
-        self.offer(offer_id).set(&offer);
+    // Bir adresi engellenen adresler listesinden çıkarır
+    #[endpoint(unblockAddress)]
+    fn unblock_address(&self, address: ManagedAddress) {
+        self.require_caller_has_role(Role::ComplianceOfficer);
 
-        // send().direct_egld() ile kilitli EGLD'yi alıcıya gönderiyoruz
-        // Bu transfer güvenli ve atomiktir - ya tamamen başarılı olur ya da işlem geri alınır
-        // Alıcı adresine ve orijinal teklif miktarına göre transfer yapılır
-        self.send().direct_egld(&caller, &offer.amount);
+        // remove() adres listede değilse false döner
+        require!(self.blocked_addresses().remove(&address), ERR_ADDRESS_NOT_BLOCKED);
 
-        // Kabul işlemini blockchain'de logluyoruz
-        // Bu sayede frontend uygulamalar ve kullanıcılar işlemi takip edebilir
-        // Event parametreleri: teklif ID'si, kabul eden adres ve miktar
-        self.accept_offer_event(offer_id, &caller, &offer.amount);
+        self.address_unblocked_event(&address);
+    }
+
+    // Engellenmiş bir tarafı olan aktif teklifi admin incelemesinden sonra iade eder
+    // Owner veya Admin rolündeki adresler çağırabilir
+    // Fonlar her zaman teklifi oluşturan adrese geri gönderilir
+    // Normal teklifler bu yoldan iptal edilemez, sadece engellenmiş taraflı teklifler için kullanılır
+    // Teslimatı başarısız olmuş (PendingDelivery) teklifler de iade edilebilir, sürmekte olan teslimat beklenmelidir
+    #[endpoint(adminRefundOffer)]
+    fn admin_refund_offer(&self, offer_id: u64) {
+        self.require_caller_has_role(Role::Admin);
+
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        let mut offer = self.offer(offer_id).get();
+
+        require!(
+            offer.status == OfferStatus::Active || offer.status == OfferStatus::PendingDelivery,
+            ERR_OFFER_NOT_ACTIVE
+        );
+        require!(!self.delivery_in_progress(offer_id).get(), ERR_DELIVERY_IN_PROGRESS);
+        require!(
+            self.blocked_addresses().contains(&offer.creator)
+                || self.blocked_addresses().contains(&offer.recipient),
+            ERR_OFFER_HAS_NO_BLOCKED_PARTY
+        );
+
+        let old_status = offer.status.clone();
+        offer.status = OfferStatus::Cancelled;
+        offer.closed_timestamp = self.blockchain().get_block_timestamp();
+        offer.closed_by = self.blockchain().get_caller();
+        self.save_offer(&mut offer);
+
+        // Engellenmiş tarafa ödeme yapılmaması için getiri paylaştırılmaz, LST'nin tamamı oluşturana gider
+        // Açık artırmalarda satılan varlık oluşturana, en yüksek teklif ise sahibinin iade bakiyesine eklenir
+        // Açıklanmamış teminatlar ceza kesilmeden sahiplerinin iade bakiyesine eklenir
+        // Satış tekliflerinde satılmamış kalan miktar oluşturana iade edilir
+        if !self.auction(offer_id).is_empty() {
+            self.send_auction_asset(&offer, &offer.creator);
+            let highest_bid = self.highest_bid(offer_id).take();
+            if highest_bid > 0u32 {
+                self.credit_refund(offer_id, &offer.recipient, &highest_bid);
+            }
+            for (bidder, sealed_bid) in self.sealed_bids(offer_id).iter() {
+                self.credit_refund(offer_id, &bidder, &sealed_bid.deposit);
+            }
+            self.sealed_bids(offer_id).clear();
+        } else if !self.sell_offer(offer_id).is_empty() {
+            self.send_sell_offer_remainder(&offer, &offer.creator);
+        } else if self.staking_position(offer_id).is_empty() {
+            self.send().direct_egld(&offer.creator, &offer.amount);
+        } else {
+            self.close_staking_position(&offer, &offer.creator, false);
+        }
+
+        self.admin_refund_offer_event(
+            offer_id,
+            &offer.creator,
+            &offer.recipient,
+            &self.offer_event_data(&offer, Some(old_status))
+        );
 
-        // İşlem başarılı - Ok(()) ile başarı durumunu dönüyoruz
-        // () unit type, değer döndürmediğimizi ama işlemin başarılı olduğunu belirtir
-        multiversx_sc::types::SCResult::Ok(())
+        self.record_offer_closed(&offer);
     }
 
     // Storage Mappers - Blockchain'de veri depolama yapıları
@@ -267,9 +2352,7 @@ pub trait EscrowContract {
     fn last_offer_id(&self) -> SingleValueMapper<u64>;
 
     // Teklif bilgilerini ID'ye göre tutan mapper
-    // #[view] ile dışarıdan okunabilir yapılır
-    // getOffer ismi ile frontend'den çağrılabilir
-    #[view(getOffer)]
+    // Dışarıdan getOffer view'ı ile okunur, arşivlenmiş teklifler burada yer almaz
     // "offer" string'i storage key olarak kullanılır
     // Her teklif için ID'ye göre ayrı bir storage alanı oluşturulur
     #[storage_mapper("offer")]
@@ -302,6 +2385,189 @@ pub trait EscrowContract {
     // ManagedAddress referansı ile bellek optimizasyonu sağlanır
     fn user_incoming_offers(&self, user: &ManagedAddress) -> SetMapper<u64>;
 
+    // (oluşturan, harici referans) çiftinden teklif ID'sine eşleme
+    // Referansın oluşturan başına benzersiz olmasını bu mapper ile sağlıyoruz
+    #[storage_mapper("offerByReference")]
+    fn offer_by_reference(
+        &self,
+        creator: &ManagedAddress,
+        reference: &ManagedBuffer
+    ) -> SingleValueMapper<u64>;
+
+    // Engellenen adresleri tutan mapper
+    // #[view] ile compliance ekibi listeyi dışarıdan sorgulayabilir
+    #[view(getBlockedAddresses)]
+    #[storage_mapper("blockedAddresses")]
+    fn blocked_addresses(&self) -> SetMapper<ManagedAddress>;
+
+    // Her rol için o role sahip adresleri tutan mapper
+    // getRoleHolders ile rol bazında yetkili adresler listelenebilir
+    #[view(getRoleHolders)]
+    #[storage_mapper("roleHolders")]
+    fn role_holders(&self, role: Role) -> SetMapper<ManagedAddress>;
+
+    // Admin rolü önerilmiş ancak henüz kabul etmemiş adresler
+    #[view(getPendingAdmins)]
+    #[storage_mapper("pendingAdmins")]
+    fn pending_admins(&self) -> SetMapper<ManagedAddress>;
+
+    // Kontratın versiyonlu kodlamayı kullanan bir sürümle deploy/upgrade edilip edilmediği
+    // Versiyonsuz ilk sürümle deploy edilmiş kontratlarda bu kayıt yoktur
+    #[storage_mapper("versionedStorage")]
+    fn versioned_storage(&self) -> SingleValueMapper<bool>;
+
+    // Upgrade anında var olan (eski kodlamalı) teklif sayısı
+    // Bu ID'ye kadar olan teklifler eski kodlamada olabilir
+    #[storage_mapper("legacyOfferCount")]
+    fn legacy_offer_count(&self) -> SingleValueMapper<u64>;
+
+    // Henüz güncel kodlamaya dönüştürülmemiş eski teklif sayısı
+    #[storage_mapper("legacyOffersRemaining")]
+    fn legacy_offers_remaining(&self) -> SingleValueMapper<u64>;
+
+    // Arşivlenmiş tekliflerin özetlerini tutan mapper
+    #[view(getArchivedOffer)]
+    #[storage_mapper("archivedOffer")]
+    fn archived_offer(&self, id: u64) -> SingleValueMapper<OfferSummary<Self::Api>>;
+
+    // Sonuçlanan bir teklifin arşivlenebilmesi için geçmesi gereken süre (saniye)
+    #[view(getArchiveRetentionPeriod)]
+    #[storage_mapper("archiveRetentionPeriod")]
+    fn archive_retention_period(&self) -> SingleValueMapper<u64>;
+
+    // İstatistik sayaçları
+    // İstatistiklere dahil edilen ilk teklif ID'si, daha küçük ID'ler upgrade öncesi tekliflerdir
+    // Değer en az 1 olduğu için storage hiçbir zaman boş kalmaz ve set_if_empty güvenle kullanılabilir
+    #[storage_mapper("statsFirstOfferId")]
+    fn stats_first_offer_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("totalOffersCreated")]
+    fn total_offers_created(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("activeOfferCount")]
+    fn active_offer_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("completedOfferCount")]
+    fn completed_offer_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("cancelledOfferCount")]
+    fn cancelled_offer_count(&self) -> SingleValueMapper<u64>;
+
+    // İstatistiği tutulan token'lar
+    #[storage_mapper("statsTokens")]
+    fn stats_tokens(&self) -> SetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("valueLocked")]
+    fn value_locked(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("volume")]
+    fn volume(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // Adres bazında işlem geçmişi sayaçları
+    // Admin iadeleri oluşturanın kararı olmadığı için iptal sayacına işlenmez
+    // Hiç işlem yapmamış adresler için kayıt yoktur, okuma getUserReputation / update_reputation üzerinden yapılmalıdır
+    #[storage_mapper("userReputation")]
+    fn user_reputation(&self, user: &ManagedAddress) -> SingleValueMapper<UserReputation>;
+
+    // Teklif için tanımlanmış teslimat çağrısı, tanımlı değilse fonlar doğrudan transfer edilir
+    // Dışarıdan getDeliveryCall view'ı ile okunur
+    #[storage_mapper("deliveryCall")]
+    fn delivery_call(&self, offer_id: u64) -> SingleValueMapper<DeliveryCall<Self::Api>>;
+
+    // Teklifin tryRelease ile serbest bırakılma koşulu
+    // Dışarıdan getReleaseCondition view'ı ile okunur
+    #[storage_mapper("releaseCondition")]
+    fn release_condition(&self, offer_id: u64) -> SingleValueMapper<ReleaseCondition<Self::Api>>;
+
+    // USD cinsinden tekliflerde alıcıya borçlu olunan miktar (sent), EGLD cinsinden tekliflerde boştur
+    #[view(getUsdAmount)]
+    #[storage_mapper("usdAmount")]
+    fn usd_amount(&self, offer_id: u64) -> SingleValueMapper<BigUint>;
+
+    // USD cinsinden tekliflerin fiyatının okunduğu oracle
+    // Dışarıdan getPriceOracle view'ı ile okunur
+    #[storage_mapper("priceOracle")]
+    fn price_oracle(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Kabul edilen en eski oracle fiyatının yaşı (saniye)
+    #[view(getMaxPriceAge)]
+    #[storage_mapper("maxPriceAge")]
+    fn max_price_age(&self) -> SingleValueMapper<u64>;
+
+    // EGLD'yi WEGLD'ye çeviren wrapper kontratı
+    // Dışarıdan getEgldWrapper view'ı ile okunur
+    #[storage_mapper("egldWrapper")]
+    fn egld_wrapper(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Çıktı tokenı -> WEGLD/token pair kontratı
+    #[storage_mapper("swapPairs")]
+    fn swap_pairs(&self) -> MapMapper<TokenIdentifier, ManagedAddress>;
+
+    // Ödeme olarak kabul edilen ESDT'ler
+    #[view(getAllowedTokens)]
+    #[storage_mapper("allowedTokens")]
+    fn allowed_tokens(&self) -> SetMapper<TokenIdentifier>;
+
+    // Token -> kabul edilen teklif miktarı aralığı
+    // Dışarıdan getTokenLimits view'ı ile okunur
+    #[storage_mapper("tokenLimits")]
+    fn token_limits(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, TokenLimits<Self::Api>>;
+
+    // Kilitli EGLD'nin yatırıldığı likit staking kontratı
+    // Dışarıdan getLiquidStaking view'ı ile okunur
+    #[storage_mapper("liquidStaking")]
+    fn liquid_staking(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Yeni tekliflerde getiriden alıcıya düşecek pay (baz puan)
+    #[view(getRecipientYieldShare)]
+    #[storage_mapper("recipientYieldShare")]
+    fn recipient_yield_share(&self) -> SingleValueMapper<u32>;
+
+    // Likit staking modundaki tekliflerin LST pozisyonu, teklif kapanınca silinir
+    // Dışarıdan getStakingPosition view'ı ile okunur
+    #[storage_mapper("stakingPosition")]
+    fn staking_position(&self, offer_id: u64) -> SingleValueMapper<StakingPosition<Self::Api>>;
+
+    // Açık artırma tekliflerinin ayarları, diğer tekliflerde boştur
+    // Dışarıdan getAuction view'ı ile okunur
+    #[storage_mapper("auction")]
+    fn auction(&self, offer_id: u64) -> SingleValueMapper<Auction<Self::Api>>;
+
+    // English açık artırmada en yüksek teklif, Dutch açık artırmada ödenen fiyat
+    // Teklifin sahibi Offer'ın recipient alanıdır
+    #[view(getHighestBid)]
+    #[storage_mapper("highestBid")]
+    fn highest_bid(&self, offer_id: u64) -> SingleValueMapper<BigUint>;
+
+    // Açık artırmalardan adrese iade edilmeyi bekleyen EGLD, withdrawRefund ile çekilir
+    #[view(getPendingRefund)]
+    #[storage_mapper("pendingRefunds")]
+    fn pending_refunds(&self, bidder: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Kapalı teklifli açık artırmada henüz açıklanmamış teklifler (teklif veren -> teklif)
+    // Açıklanan teklifler silinir, kalanlar settleAuction ile cezalandırılır
+    #[storage_mapper("sealedBids")]
+    fn sealed_bids(&self, offer_id: u64) -> MapMapper<ManagedAddress, SealedBid<Self::Api>>;
+
+    // Yeni kapalı teklifli açık artırmalarda açıklanmayan teminattan kesilecek pay (baz puan)
+    #[view(getUnrevealedBidPenalty)]
+    #[storage_mapper("unrevealedBidPenalty")]
+    fn unrevealed_bid_penalty(&self) -> SingleValueMapper<u32>;
+
+    // Kısmi satışlı açık satış tekliflerinin ayarları
+    #[storage_mapper("sellOffer")]
+    fn sell_offer(&self, offer_id: u64) -> SingleValueMapper<SellOffer<Self::Api>>;
+
+    // Satış teklifinden alım yapmış adresler
+    // Offer.recipient sadece son alıcıyı tuttuğu için arşivlemede alıcıların listelerini temizlemek için kullanılır
+    #[storage_mapper("sellOfferBuyers")]
+    fn sell_offer_buyers(&self, offer_id: u64) -> SetMapper<ManagedAddress>;
+
+    // Teslimat çağrısı gönderilmiş ve sonucu henüz gelmemiş teklifler
+    #[view(isDeliveryInProgress)]
+    #[storage_mapper("deliveryInProgress")]
+    fn delivery_in_progress(&self, offer_id: u64) -> SingleValueMapper<bool>;
+
     // Events - Blockchain'de kaydedilen olaylar
     // Eventler blockchain'de kalıcı olarak loglanır
     // Frontend uygulamalar bu eventleri dinleyerek değişiklikleri takip edebilir
@@ -311,12 +2577,14 @@ pub trait EscrowContract {
     #[event("createOffer")]
     // Event parametreleri #[indexed] ile işaretlenir
     // Bu sayede parametrelere göre filtreleme/arama yapılabilir
+    // Tüm yaşam döngüsü eventleri aynı şemayı kullanır: ID ve taraflar topic'lerde,
+    // ödeme, eski/yeni durum, zaman ve metadata ise event data alanında (OfferEventData)
     fn create_offer_event(
         &self,
         #[indexed] offer_id: u64,        // Teklifin benzersiz ID'si
         #[indexed] creator: &ManagedAddress,  // Teklifi oluşturan adres
         #[indexed] recipient: &ManagedAddress, // Alıcı adresi
-        #[indexed] amount: &BigUint       // Teklif miktarı (EGLD)
+        data: &OfferEventData<Self::Api> // Teklifin anlık görüntüsü (event data alanında)
     );
 
     // Teklif iptal olayı
@@ -326,7 +2594,8 @@ pub trait EscrowContract {
         &self,
         #[indexed] offer_id: u64,        // İptal edilen teklifin ID'si
         #[indexed] creator: &ManagedAddress,  // Teklifi iptal eden (oluşturan) adres
-        #[indexed] amount: &BigUint       // İade edilen miktar
+        #[indexed] recipient: &ManagedAddress, // Alıcı adresi
+        data: &OfferEventData<Self::Api> // İade edilen ödeme ve durum geçişi
     );
 
     // Teklif kabul olayı
@@ -335,13 +2604,384 @@ pub trait EscrowContract {
     fn accept_offer_event(
         &self,
         #[indexed] offer_id: u64,        // Kabul edilen teklifin ID'si
+        #[indexed] creator: &ManagedAddress,  // Teklifi oluşturan adres
         #[indexed] recipient: &ManagedAddress, // Teklifi kabul eden (alıcı) adres
-        #[indexed] amount: &BigUint       // Transfer edilen miktar
+        data: &OfferEventData<Self::Api> // Transfer edilen ödeme ve durum geçişi
+    );
+    // Oluşturanın fonları alıcıya serbest bırakma olayı
+    #[event("releaseOffer")]
+    fn release_offer_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
+        data: &OfferEventData<Self::Api>
+    );
+
+    // Teslimat çağrısının başarıyla tamamlanma olayı (PendingDelivery -> Completed)
+    #[event("deliveryCompleted")]
+    fn delivery_completed_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
+        data: &OfferEventData<Self::Api>
+    );
+
+    // Teslimat çağrısının başarısız olma olayı, teklif PendingDelivery durumunda kalır
+    #[event("deliveryFailed")]
+    fn delivery_failed_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
+        data: &OfferEventData<Self::Api>
+    );
+
+    // Koşul sağlandığı için fonların tryRelease ile serbest bırakılma olayı
+    #[event("conditionalRelease")]
+    fn conditional_release_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
+        data: &OfferEventData<Self::Api>
+    );
+
+    // USD cinsinden teklifin ödeme miktarının hesaplanma olayı
+    // Ödenen EGLD miktarı teklifin kabul / serbest bırakma eventinde yer alır
+    #[event("usdOfferSettled")]
+    fn usd_offer_settled_event(
+        &self,
+        #[indexed] offer_id: u64,
+        data: &UsdOfferSettledEventData<Self::Api>
+    );
+
+    // Ödemenin alıcı için başka bir tokena takas edilme olayı
+    // Takas edilen EGLD miktarı acceptOffer eventinde yer alır
+    #[event("payoutSwapped")]
+    fn payout_swapped_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] token_out: &TokenIdentifier,
+        amount_out: &BigUint
+    );
+
+    // Teklifin kilitli EGLD'sinin likit staking kontratına yatırılma olayı
+    #[event("offerStaked")]
+    fn offer_staked_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] ls_token: &TokenIdentifier,
+        ls_amount: &BigUint
+    );
+
+    // Teklifin LST'sinin taraflara dağıtılma olayı
+    // Miktarlar LST cinsindendir, anapara fonları alan tarafa gider
+    #[event("stakingPositionClosed")]
+    fn staking_position_closed_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] ls_token: &TokenIdentifier,
+        data: &StakingPositionClosedEventData<Self::Api>
+    );
+
+    // English açık artırmaya teklif verilme olayı
+    #[event("auctionBid")]
+    fn auction_bid_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        amount: &BigUint
+    );
+
+    // Geçilen veya iade edilen teklifin iade bakiyesine eklenme olayı
+    #[event("refundCredited")]
+    fn refund_credited_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        amount: &BigUint
+    );
+
+    // İade bakiyesinin çekilme olayı
+    #[event("refundWithdrawn")]
+    fn refund_withdrawn_event(&self, #[indexed] bidder: &ManagedAddress, amount: &BigUint);
+
+    // Kapalı teklifli açık artırmaya teklif verilme olayı, sadece teminat görünür
+    #[event("sealedBidCommitted")]
+    fn sealed_bid_committed_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        deposit: &BigUint
+    );
+
+    // Kapalı teklifin açıklanma olayı
+    #[event("sealedBidRevealed")]
+    fn sealed_bid_revealed_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        amount: &BigUint
+    );
+
+    // Açıklanmayan teklifin teminatından ceza kesilme olayı
+    #[event("unrevealedBidPenalized")]
+    fn unrevealed_bid_penalized_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        penalty: &BigUint
+    );
+
+    // Açık artırmanın kazananla sonuçlanma olayı
+    // Varlığın aktarımı acceptOffer eventinde yer alır, bu event ödenen fiyatı kaydeder
+    #[event("auctionSettled")]
+    fn auction_settled_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] winner: &ManagedAddress,
+        price: &BigUint
+    );
+
+    // Satış teklifinden kısmi alım olayı
+    // Her alımda yayınlanır, kalan miktar sıfırlandığında ayrıca acceptOffer eventi yayınlanır
+    #[event("partialFill")]
+    fn partial_fill_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        data: &PartialFillEventData<Self::Api>
+    );
+
+    // Adresin engellenen adresler listesine eklenme olayı
+    #[event("addressBlocked")]
+    fn address_blocked_event(&self, #[indexed] address: &ManagedAddress);
+
+    // Adresin engellenen adresler listesinden çıkarılma olayı
+    #[event("addressUnblocked")]
+    fn address_unblocked_event(&self, #[indexed] address: &ManagedAddress);
+
+    // Teklif arşivlenme olayı
+    #[event("archiveOffer")]
+    fn archive_offer_event(&self, #[indexed] offer_id: u64);
+
+    // Rol verilme olayı
+    #[event("roleGranted")]
+    fn role_granted_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    // Rol geri alınma olayı
+    #[event("roleRevoked")]
+    fn role_revoked_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    // Admin rolü önerilme olayı (iki adımlı sürecin ilk adımı)
+    #[event("adminRoleProposed")]
+    fn admin_role_proposed_event(&self, #[indexed] address: &ManagedAddress);
+
+    // Bekleyen Admin önerisinin geri çekilme olayı
+    #[event("adminRoleProposalCancelled")]
+    fn admin_role_proposal_cancelled_event(&self, #[indexed] address: &ManagedAddress);
+
+    // Admin tarafından yapılan iade olayı
+    #[event("adminRefundOffer")]
+    fn admin_refund_offer_event(
+        &self,
+        #[indexed] offer_id: u64,        // İade edilen teklifin ID'si
+        #[indexed] creator: &ManagedAddress,  // İadeyi alan (oluşturan) adres
+        #[indexed] recipient: &ManagedAddress, // Alıcı adresi
+        data: &OfferEventData<Self::Api> // İade edilen ödeme ve durum geçişi
     );
+
     // View fonksiyonları - Blockchain'den sadece veri okuma işlemleri yapan fonksiyonlardır
     // Bu fonksiyonlar blockchain'i değiştirmez, sadece mevcut durumu sorgular
     // Gas maliyeti düşüktür çünkü durum değişikliği yapmazlar
 
+    // Teklif bilgilerini ID'ye göre getiren fonksiyon
+    // getOffer ismi ile frontend'den çağrılabilir
+    // Var olmayan veya arşivlenmiş teklifler için boş sonuç döner
+    // Arşivlenmiş teklifin özeti getArchivedOffer ile okunabilir
+    #[view(getOffer)]
+    fn get_offer(&self, id: u64) -> OptionalValue<Offer<Self::Api>> {
+        if self.offer(id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.offer(id).get())
+    }
+
+    // Teklifin teslimat çağrısını getiren fonksiyon
+    // Teslimat çağrısı tanımlanmamış teklifler için boş sonuç döner
+    #[view(getDeliveryCall)]
+    fn get_delivery_call(&self, offer_id: u64) -> OptionalValue<DeliveryCall<Self::Api>> {
+        if self.delivery_call(offer_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.delivery_call(offer_id).get())
+    }
+
+    // Teklifin serbest bırakma koşulunu getiren fonksiyon
+    // Koşulu olmayan teklifler için boş sonuç döner
+    #[view(getReleaseCondition)]
+    fn get_release_condition(&self, offer_id: u64) -> OptionalValue<ReleaseCondition<Self::Api>> {
+        if self.release_condition(offer_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.release_condition(offer_id).get())
+    }
+
+    // USD cinsinden teklifler için ayarlanmış fiyat oracle'ını getiren fonksiyon
+    // Oracle ayarlanmamışsa boş sonuç döner
+    #[view(getPriceOracle)]
+    fn get_price_oracle(&self) -> OptionalValue<ManagedAddress> {
+        if self.price_oracle().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.price_oracle().get())
+    }
+
+    // Takas için ayarlanmış wrapper kontratını getiren fonksiyon
+    // Wrapper ayarlanmamışsa boş sonuç döner
+    #[view(getEgldWrapper)]
+    fn get_egld_wrapper(&self) -> OptionalValue<ManagedAddress> {
+        if self.egld_wrapper().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.egld_wrapper().get())
+    }
+
+    // Takas edilebilecek tokenlar ve pair kontratları
+    #[view(getSwapPairs)]
+    fn get_swap_pairs(&self) -> MultiValueEncoded<MultiValue2<TokenIdentifier, ManagedAddress>> {
+        let mut pairs = MultiValueEncoded::new();
+        for (token_out, pair) in self.swap_pairs().iter() {
+            pairs.push((token_out, pair).into());
+        }
+
+        pairs
+    }
+
+    // Miktar sınırı ayarlanmış tokenlar ve sınırları
+    #[view(getTokenLimits)]
+    fn get_token_limits(&self) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, TokenLimits<Self::Api>>> {
+        let mut limits = MultiValueEncoded::new();
+        for (token, token_limits) in self.token_limits().iter() {
+            limits.push((token, token_limits).into());
+        }
+
+        limits
+    }
+
+    // Kilitli EGLD'nin yatırıldığı likit staking kontratını getiren fonksiyon
+    // Kontrat ayarlanmamışsa boş sonuç döner
+    #[view(getLiquidStaking)]
+    fn get_liquid_staking(&self) -> OptionalValue<ManagedAddress> {
+        if self.liquid_staking().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.liquid_staking().get())
+    }
+
+    // Teklifin LST pozisyonunu getiren fonksiyon
+    // Likit staking modunda olmayan veya kapanmış tekliflerde boş sonuç döner
+    #[view(getStakingPosition)]
+    fn get_staking_position(&self, offer_id: u64) -> OptionalValue<StakingPosition<Self::Api>> {
+        if self.staking_position(offer_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.staking_position(offer_id).get())
+    }
+
+    // Açık artırma teklifinin ayarlarını getiren fonksiyon
+    // Açık artırma olmayan tekliflerde boş sonuç döner
+    #[view(getAuction)]
+    fn get_auction(&self, offer_id: u64) -> OptionalValue<Auction<Self::Api>> {
+        if self.auction(offer_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.auction(offer_id).get())
+    }
+
+    // Kapalı teklif için verilmesi gereken hash: keccak256((bidder, amount, salt) top-encoded)
+    // Teklif veren hash'i kendisi de hesaplayabilir, view sadece kolaylık içindir
+    #[view(computeBidHash)]
+    fn compute_bid_hash(&self, bidder: ManagedAddress, amount: BigUint, salt: ManagedBuffer) -> ManagedByteArray<32> {
+        let encoded = self.serializer().top_encode_to_managed_buffer(&(bidder, amount, salt));
+        self.crypto().keccak256(encoded)
+    }
+
+    // Kapalı teklifli açık artırmada henüz açıklanmamış teklifler ve teminatları
+    #[view(getSealedBids)]
+    fn get_sealed_bids(&self, offer_id: u64) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut bids = MultiValueEncoded::new();
+        for (bidder, sealed_bid) in self.sealed_bids(offer_id).iter() {
+            bids.push((bidder, sealed_bid.deposit).into());
+        }
+
+        bids
+    }
+
+    // Dutch açık artırmanın şu anki fiyatını getiren fonksiyon
+    #[view(getDutchAuctionPrice)]
+    fn get_dutch_auction_price(&self, offer_id: u64) -> BigUint {
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        require!(!self.auction(offer_id).is_empty(), ERR_WRONG_AUCTION_KIND);
+
+        let auction = self.auction(offer_id).get();
+        require!(auction.kind == AuctionKind::Dutch, ERR_WRONG_AUCTION_KIND);
+        self.dutch_auction_price(&self.offer(offer_id).get(), &auction)
+    }
+
+    // Satış teklifinin ayarlarını getiren fonksiyon
+    // Satış teklifi olmayan tekliflerde boş sonuç döner
+    #[view(getSellOffer)]
+    fn get_sell_offer(&self, offer_id: u64) -> OptionalValue<SellOffer<Self::Api>> {
+        if self.sell_offer(offer_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.sell_offer(offer_id).get())
+    }
+
+    // Satış teklifinden amount kadar alımın EGLD fiyatını getiren fonksiyon
+    #[view(getFillPrice)]
+    fn get_fill_price(&self, offer_id: u64, amount: BigUint) -> BigUint {
+        require!(!self.offer(offer_id).is_empty(), ERR_OFFER_NOT_FOUND);
+        require!(!self.sell_offer(offer_id).is_empty(), ERR_NOT_SELL_OFFER);
+
+        self.fill_price(&self.offer(offer_id).get(), &self.sell_offer(offer_id).get(), &amount)
+    }
+
+    // Dashboard için genel istatistikleri getiren fonksiyon
+    // Indexer gerektirmeden toplam, aktif, tamamlanan ve iptal edilen teklif sayıları ile
+    // token bazında kilitli değer ve toplam hacim okunabilir
+    #[view(getStats)]
+    fn get_stats(&self) -> EscrowStats<Self::Api> {
+        let mut tokens = ManagedVec::new();
+        for token in self.stats_tokens().iter() {
+            tokens.push(TokenStats {
+                value_locked: self.value_locked(&token).get(),
+                volume: self.volume(&token).get(),
+                token
+            });
+        }
+
+        EscrowStats {
+            total_created: self.total_offers_created().get(),
+            active_count: self.active_offer_count().get(),
+            completed_count: self.completed_offer_count().get(),
+            cancelled_count: self.cancelled_offer_count().get(),
+            tokens
+        }
+    }
+
     // Tüm aktif teklifleri getiren fonksiyon
     // #[view] attribute'u bu fonksiyonun dışarıdan okunabilir olduğunu belirtir
     // getActiveOffers ismi ile frontend'den çağrılabilir
@@ -359,6 +2999,11 @@ pub trait EscrowContract {
         // ..= operatörü son değeri de dahil eder (inclusive range)
         // last_offer_id().get() ile storage'dan son ID değeri okunur
         for offer_id in 1..=self.last_offer_id().get() {
+            // Arşivlenmiş teklifler storage'da yer almaz, atlanır
+            if self.offer(offer_id).is_empty() {
+                continue;
+            }
+
             // offer() storage mapper'ı ile ID'ye karşılık gelen teklif bilgileri alınır
             // get() ile storage'dan veri okunur
             let offer = self.offer(offer_id).get();
@@ -404,6 +3049,11 @@ pub trait EscrowContract {
         // Bu şekilde kullanıcının tüm tekliflerini kontrol edebiliyoruz
         // Storage mapper'lar blockchain storage'ına erişim sağlar
         for offer_id in self.user_offers(user).iter() {
+            // Kaydı silinmiş (arşivlenmiş) teklifler atlanır
+            if self.offer(offer_id).is_empty() {
+                continue;
+            }
+
             // offer() storage mapper'ı ile her ID'ye karşılık gelen teklif bilgilerini okuyoruz
             // get() metodu storage'dan veriyi çeker
             // Bu işlem her döngüde bir storage okuma maliyeti oluşturur
@@ -450,6 +3100,11 @@ pub trait EscrowContract {
         // iter() metodu ile bu ID'ler üzerinde döngü kuruyoruz
         // Bu şekilde tüm gelen teklifleri kontrol edebiliyoruz
         for offer_id in self.user_incoming_offers(user).iter() {
+            // Kaydı silinmiş (arşivlenmiş) teklifler atlanır
+            if self.offer(offer_id).is_empty() {
+                continue;
+            }
+
             // offer() storage mapper'ı ile her ID'ye karşılık gelen teklif bilgilerini okuyoruz
             // get() metodu storage'dan veriyi çeker
             // Bu işlem her döngüde bir storage okuma maliyeti oluşturur
@@ -468,4 +3123,130 @@ pub trait EscrowContract {
         
         result
     }
-}
\ No newline at end of file
+
+    // Merkezi hata kataloğunu döndürür
+    // Sonuç: her hata için (hata kodu, işlem geri alındığında dönen mesaj)
+    // İstemciler bu listeyi kullanarak hata mesajlarını koda eşleyebilir
+    #[view(getErrorCatalogue)]
+    fn get_error_catalogue(&self) -> MultiValueEncoded<MultiValue2<EscrowError, ManagedBuffer>> {
+        let mut result = MultiValueEncoded::new();
+        for error in EscrowError::ALL {
+            result.push((*error, ManagedBuffer::from(error.message())).into());
+        }
+
+        result
+    }
+
+    // Storage migration ilerlemesini döndürür
+    // Sonuç: (upgrade anındaki eski teklif sayısı, henüz dönüştürülmemiş teklif sayısı)
+    #[view(getMigrationProgress)]
+    fn get_migration_progress(&self) -> MultiValue2<u64, u64> {
+        (self.legacy_offer_count().get(), self.legacy_offers_remaining().get()).into()
+    }
+
+    // Adresin belirtilen role sahip olup olmadığını döndürür
+    // Owner tüm rollere sahip kabul edilir
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: ManagedAddress) -> bool {
+        address == self.blockchain().get_owner_address() || self.role_holders(role).contains(&address)
+    }
+
+    // Adresin engellenmiş olup olmadığını döndürür
+    #[view(isAddressBlocked)]
+    fn is_address_blocked(&self, address: ManagedAddress) -> bool {
+        self.blocked_addresses().contains(&address)
+    }
+
+    // Adresin işlem geçmişi sayaçlarını döndürür
+    // Hiç işlem yapmamış adresler için tüm sayaçlar 0 döner
+    #[view(getUserReputation)]
+    fn get_user_reputation(&self, user: ManagedAddress) -> UserReputation {
+        let reputation_mapper = self.user_reputation(&user);
+        if reputation_mapper.is_empty() {
+            return UserReputation::default();
+        }
+
+        reputation_mapper.get()
+    }
+
+    // Oluşturan adres ve harici referans ile teklifi getiren fonksiyon
+    // ERP gibi dış sistemler kendi fatura numaraları ile teklifi bulabilir
+    // Referansa ait teklif yoksa veya teklif arşivlenmişse boş sonuç döner
+    #[view(getOfferByReference)]
+    fn get_offer_by_reference(
+        &self,
+        creator: ManagedAddress,
+        reference: ManagedBuffer
+    ) -> OptionalValue<Offer<Self::Api>> {
+        let offer_id_mapper = self.offer_by_reference(&creator, &reference);
+        if offer_id_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        self.get_offer(offer_id_mapper.get())
+    }
+
+    // Kullanıcının oluşturduğu ve sonuçlanmış (kabul/iptal edilmiş) teklifleri getiren fonksiyon
+    // Frontend'deki geçmiş sekmesi için kullanılır
+    // Sonuçlar en yeni tekliften eskiye doğru sıralanır
+    // offset kadar sonuçlanmış teklif atlanır, en fazla limit kadar teklif döner
+    #[view(getUserClosedOffers)]
+    fn get_user_closed_offers(
+        &self,
+        user: ManagedAddress,
+        offset: usize,
+        limit: usize
+    ) -> MultiValueEncoded<Offer<Self::Api>> {
+        self.get_closed_offers_page(&self.user_offers(&user), offset, limit)
+    }
+
+    // Kullanıcıya gelen ve sonuçlanmış (kabul/iptal edilmiş) teklifleri getiren fonksiyon
+    // Sıralama ve sayfalama getUserClosedOffers ile aynıdır
+    #[view(getUserIncomingClosedOffers)]
+    fn get_user_incoming_closed_offers(
+        &self,
+        user: ManagedAddress,
+        offset: usize,
+        limit: usize
+    ) -> MultiValueEncoded<Offer<Self::Api>> {
+        self.get_closed_offers_page(&self.user_incoming_offers(&user), offset, limit)
+    }
+
+    // Verilen ID kümesindeki sonuçlanmış tekliflerden bir sayfa oluşturur
+    // SetMapper ekleme sırasını koruduğu için kümeyi sondan başa dolaşarak en yeni teklifler önce gelir
+    fn get_closed_offers_page(
+        &self,
+        offer_ids: &SetMapper<Self::Api, u64>,
+        offset: usize,
+        limit: usize
+    ) -> MultiValueEncoded<Offer<Self::Api>> {
+        require!(limit <= MAX_PAGE_SIZE, ERR_PAGE_SIZE_TOO_LARGE);
+
+        let mut result = MultiValueEncoded::new();
+        let mut skipped = 0usize;
+        let mut collected = 0usize;
+        let mut current = offer_ids.back();
+        while let Some(offer_id) = current {
+            if collected == limit {
+                break;
+            }
+
+            // Kaydı silinmiş (arşivlenmiş) teklifler atlanır
+            if !self.offer(offer_id).is_empty() {
+                let offer = self.offer(offer_id).get();
+                if offer.status.is_closed() {
+                    if skipped < offset {
+                        skipped += 1;
+                    } else {
+                        result.push(offer);
+                        collected += 1;
+                    }
+                }
+            }
+
+            current = offer_ids.previous(&offer_id);
+        }
+
+        result
+    }
+}
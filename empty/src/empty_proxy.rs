@@ -0,0 +1,1428 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct EscrowContractProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for EscrowContractProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = EscrowContractProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        EscrowContractProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct EscrowContractProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> EscrowContractProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> EscrowContractProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> EscrowContractProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn create<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+        Arg3: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+        Arg4: ProxyArg<OptionalValue<u64>>,
+        Arg5: ProxyArg<OptionalValue<Option<ReleaseCondition<Env::Api>>>>,
+        Arg6: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg7: ProxyArg<OptionalValue<bool>>,
+    >(
+        self,
+        buyer: Arg0,
+        opt_memo: Arg1,
+        opt_reference: Arg2,
+        opt_terms_hash: Arg3,
+        opt_min_recipient_completed_trades: Arg4,
+        opt_release_condition: Arg5,
+        opt_usd_amount: Arg6,
+        opt_stake: Arg7,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("create")
+            .argument(&buyer)
+            .argument(&opt_memo)
+            .argument(&opt_reference)
+            .argument(&opt_terms_hash)
+            .argument(&opt_min_recipient_completed_trades)
+            .argument(&opt_release_condition)
+            .argument(&opt_usd_amount)
+            .argument(&opt_stake)
+            .original_result()
+    }
+
+    pub fn cancel_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelOffer")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn accept_offer<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<MultiValue2<TokenIdentifier<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        offer_id: Arg0,
+        opt_swap: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptOffer")
+            .argument(&offer_id)
+            .argument(&opt_swap)
+            .original_result()
+    }
+
+    pub fn release_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseOffer")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn try_release<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("tryRelease")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn set_price_oracle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        oracle: Arg0,
+        max_price_age: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceOracle")
+            .argument(&oracle)
+            .argument(&max_price_age)
+            .original_result()
+    }
+
+    pub fn allow_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("allowToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn remove_allowed_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAllowedToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn set_token_limits<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        min_amount: Arg1,
+        max_amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTokenLimits")
+            .argument(&token)
+            .argument(&min_amount)
+            .argument(&max_amount)
+            .original_result()
+    }
+
+    pub fn remove_token_limits<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeTokenLimits")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn set_egld_wrapper<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        wrapper: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEgldWrapper")
+            .argument(&wrapper)
+            .original_result()
+    }
+
+    pub fn set_swap_pair<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        token_out: Arg0,
+        pair: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSwapPair")
+            .argument(&token_out)
+            .argument(&pair)
+            .original_result()
+    }
+
+    pub fn remove_swap_pair<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_out: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeSwapPair")
+            .argument(&token_out)
+            .original_result()
+    }
+
+    pub fn set_liquid_staking<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        liquid_staking: Arg0,
+        recipient_yield_share: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLiquidStaking")
+            .argument(&liquid_staking)
+            .argument(&recipient_yield_share)
+            .original_result()
+    }
+
+    pub fn create_auction<
+        Arg0: ProxyArg<AuctionKind>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        kind: Arg0,
+        start_price: Arg1,
+        end_price: Arg2,
+        duration: Arg3,
+        opt_reveal_duration: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("createAuction")
+            .argument(&kind)
+            .argument(&start_price)
+            .argument(&end_price)
+            .argument(&duration)
+            .argument(&opt_reveal_duration)
+            .original_result()
+    }
+
+    pub fn bid<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("bid")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn buy<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("buy")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn settle_auction<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("settleAuction")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn commit_bid<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        offer_id: Arg0,
+        bid_hash: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("commitBid")
+            .argument(&offer_id)
+            .argument(&bid_hash)
+            .original_result()
+    }
+
+    pub fn reveal_bid<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        offer_id: Arg0,
+        amount: Arg1,
+        salt: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revealBid")
+            .argument(&offer_id)
+            .argument(&amount)
+            .argument(&salt)
+            .original_result()
+    }
+
+    pub fn withdraw_refund(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawRefund")
+            .original_result()
+    }
+
+    pub fn set_unrevealed_bid_penalty<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        penalty: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUnrevealedBidPenalty")
+            .argument(&penalty)
+            .original_result()
+    }
+
+    pub fn create_sell_offer<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        price: Arg0,
+        min_fill: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("createSellOffer")
+            .argument(&price)
+            .argument(&min_fill)
+            .original_result()
+    }
+
+    pub fn accept_partial<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        offer_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("acceptPartial")
+            .argument(&offer_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_delivery_call<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        offer_id: Arg0,
+        gas_limit: Arg1,
+        endpoint: Arg2,
+        arguments: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDeliveryCall")
+            .argument(&offer_id)
+            .argument(&gas_limit)
+            .argument(&endpoint)
+            .argument(&arguments)
+            .original_result()
+    }
+
+    pub fn retry_delivery<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("retryDelivery")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn migrate_offers<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrateOffers")
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn archive_offers<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        offer_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("archiveOffers")
+            .argument(&offer_ids)
+            .original_result()
+    }
+
+    pub fn set_archive_retention_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        retention_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setArchiveRetentionPeriod")
+            .argument(&retention_period)
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn accept_admin_role(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptAdminRole")
+            .original_result()
+    }
+
+    pub fn block_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("blockAddress")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn unblock_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unblockAddress")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn admin_refund_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("adminRefundOffer")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn last_offer_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastOfferId")
+            .original_result()
+    }
+
+    pub fn user_offers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserOffers")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn user_incoming_offers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserIncomingOffers")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn blocked_addresses(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBlockedAddresses")
+            .original_result()
+    }
+
+    pub fn role_holders<
+        Arg0: ProxyArg<Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleHolders")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn pending_admins(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAdmins")
+            .original_result()
+    }
+
+    pub fn archived_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OfferSummary<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getArchivedOffer")
+            .argument(&id)
+            .original_result()
+    }
+
+    pub fn archive_retention_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getArchiveRetentionPeriod")
+            .original_result()
+    }
+
+    pub fn usd_amount<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUsdAmount")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn max_price_age(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxPriceAge")
+            .original_result()
+    }
+
+    pub fn allowed_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllowedTokens")
+            .original_result()
+    }
+
+    pub fn recipient_yield_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRecipientYieldShare")
+            .original_result()
+    }
+
+    pub fn highest_bid<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHighestBid")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn pending_refunds<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        bidder: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingRefund")
+            .argument(&bidder)
+            .original_result()
+    }
+
+    pub fn unrevealed_bid_penalty(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnrevealedBidPenalty")
+            .original_result()
+    }
+
+    pub fn delivery_in_progress<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isDeliveryInProgress")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOffer")
+            .argument(&id)
+            .original_result()
+    }
+
+    pub fn get_delivery_call<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DeliveryCall<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeliveryCall")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_release_condition<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ReleaseCondition<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReleaseCondition")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_price_oracle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceOracle")
+            .original_result()
+    }
+
+    pub fn get_egld_wrapper(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEgldWrapper")
+            .original_result()
+    }
+
+    pub fn get_swap_pairs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<TokenIdentifier<Env::Api>, ManagedAddress<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSwapPairs")
+            .original_result()
+    }
+
+    pub fn get_token_limits(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, TokenLimits<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenLimits")
+            .original_result()
+    }
+
+    pub fn get_liquid_staking(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidStaking")
+            .original_result()
+    }
+
+    pub fn get_staking_position<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<StakingPosition<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingPosition")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_auction<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Auction<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuction")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn compute_bid_hash<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        bidder: Arg0,
+        amount: Arg1,
+        salt: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("computeBidHash")
+            .argument(&bidder)
+            .argument(&amount)
+            .argument(&salt)
+            .original_result()
+    }
+
+    pub fn get_sealed_bids<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSealedBids")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_dutch_auction_price<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDutchAuctionPrice")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_sell_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<SellOffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSellOffer")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_fill_price<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        offer_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFillPrice")
+            .argument(&offer_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn get_stats(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EscrowStats<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStats")
+            .original_result()
+    }
+
+    pub fn get_active_offers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveOffers")
+            .original_result()
+    }
+
+    pub fn get_user_active_offers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserActiveOffers")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_user_incoming_active_offers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserIncomingActiveOffers")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_error_catalogue(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EscrowError, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getErrorCatalogue")
+            .original_result()
+    }
+
+    pub fn get_migration_progress(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMigrationProgress")
+            .original_result()
+    }
+
+    pub fn has_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn is_address_blocked<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAddressBlocked")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_user_reputation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UserReputation> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserReputation")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_offer_by_reference<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        creator: Arg0,
+        reference: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferByReference")
+            .argument(&creator)
+            .argument(&reference)
+            .original_result()
+    }
+
+    pub fn get_user_closed_offers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        user: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserClosedOffers")
+            .argument(&user)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_user_incoming_closed_offers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        user: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserIncomingClosedOffers")
+            .argument(&user)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ReleaseCondition<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub target: ManagedAddress<Api>,
+    pub view: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub expected: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub enum AuctionKind {
+    English,
+    Dutch,
+    Sealed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum Role {
+    Admin,
+    Pauser,
+    FeeManager,
+    Arbiter,
+    ComplianceOfficer,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OfferSummary<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub offer_id: u64,
+    pub status: OfferStatus,
+    pub amount: BigUint<Api>,
+    pub closed_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub enum OfferStatus {
+    Active,
+    Completed,
+    Cancelled,
+    PendingDelivery,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, Clone)]
+pub struct Offer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub version: u8,
+    pub offer_id: u64,
+    pub creator: ManagedAddress<Api>,
+    pub recipient: ManagedAddress<Api>,
+    pub amount: BigUint<Api>,
+    pub status: OfferStatus,
+    pub created_timestamp: u64,
+    pub metadata: OfferMetadata<Api>,
+    pub closed_timestamp: u64,
+    pub closed_by: ManagedAddress<Api>,
+    pub remaining_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OfferMetadata<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub memo: ManagedBuffer<Api>,
+    pub reference: ManagedBuffer<Api>,
+    pub terms_hash: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct DeliveryCall<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub endpoint: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub gas_limit: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TokenLimits<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub min_amount: BigUint<Api>,
+    pub max_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StakingPosition<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub liquid_staking: ManagedAddress<Api>,
+    pub ls_token: TokenIdentifier<Api>,
+    pub ls_amount: BigUint<Api>,
+    pub recipient_yield_share: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Auction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub kind: AuctionKind,
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub nonce: u64,
+    pub start_price: BigUint<Api>,
+    pub end_price: BigUint<Api>,
+    pub deadline: u64,
+    pub reveal_deadline: u64,
+    pub unrevealed_penalty: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SellOffer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub nonce: u64,
+    pub price: BigUint<Api>,
+    pub min_fill: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EscrowStats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub total_created: u64,
+    pub active_count: u64,
+    pub completed_count: u64,
+    pub cancelled_count: u64,
+    pub tokens: ManagedVec<Api, TokenStats<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TokenStats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub value_locked: BigUint<Api>,
+    pub volume: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum EscrowError {
+    PaymentZero,
+    OfferNotFound,
+    OfferNotActive,
+    NotOfferCreator,
+    NotOfferRecipient,
+    MemoTooLong,
+    ReferenceTooLong,
+    TermsHashTooLong,
+    ReferenceAlreadyUsed,
+    CreatorBlocked,
+    RecipientBlocked,
+    AddressAlreadyBlocked,
+    AddressNotBlocked,
+    OfferHasNoBlockedParty,
+    MissingRole,
+    AddressAlreadyHasRole,
+    AddressDoesNotHaveRole,
+    AdminRoleAlreadyProposed,
+    NoPendingAdminRole,
+    MigrationBatchTooLarge,
+    OfferNotFinished,
+    RetentionPeriodNotElapsed,
+    ArchiveBatchTooLarge,
+    PageSizeTooLarge,
+    RecipientReputationTooLow,
+    RecipientNotContract,
+    DeliveryEndpointEmpty,
+    OfferNotPendingDelivery,
+    DeliveryInProgress,
+    NotOfferParty,
+    InvalidReleaseCondition,
+    NoReleaseCondition,
+    ReleaseConditionNotMet,
+    PriceOracleNotSet,
+    InvalidPriceOracle,
+    InvalidOraclePrice,
+    InsufficientCollateral,
+    SwapRouteNotSet,
+    SwapWithDeliveryCall,
+    SwapOutputTooLow,
+    InvalidSwapContract,
+    LiquidStakingNotSet,
+    InvalidLiquidStaking,
+    InvalidYieldShare,
+    NotSupportedForStakedOffer,
+    InvalidAuction,
+    NotSupportedForAuction,
+    WrongAuctionKind,
+    AuctionEnded,
+    AuctionNotEnded,
+    BidTooLow,
+    AuctionHasBids,
+    CreatorCannotBid,
+    BidAlreadyCommitted,
+    TooManySealedBids,
+    RevealNotOpen,
+    NoSealedBid,
+    BidHashMismatch,
+    BidExceedsDeposit,
+    InvalidPenalty,
+    InvalidSellOffer,
+    NotSellOffer,
+    NotSupportedForSellOffer,
+    CreatorCannotFill,
+    InvalidFillAmount,
+    FillTooSmall,
+    FillPaymentTooLow,
+    SelfOffer,
+    NoPendingRefund,
+    InvalidMaxPriceAge,
+    TokenNotAllowed,
+    TokenAlreadyAllowed,
+    InvalidTokenLimits,
+    TokenLimitsNotSet,
+    AmountBelowTokenMin,
+    AmountAboveTokenMax,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Default, Clone)]
+pub struct UserReputation {
+    pub offers_created: u64,
+    pub completed_as_creator: u64,
+    pub cancelled_as_creator: u64,
+    pub accepted_as_recipient: u64,
+    pub disputes_lost: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OfferEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub payment: EgldOrEsdtTokenPayment<Api>,
+    pub old_status: Option<OfferStatus>,
+    pub new_status: OfferStatus,
+    pub timestamp: u64,
+    pub metadata: OfferMetadata<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct UsdOfferSettledEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub usd_amount: BigUint<Api>,
+    pub price: BigUint<Api>,
+    pub refund: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StakingPositionClosedEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub principal: BigUint<Api>,
+    pub creator_yield: BigUint<Api>,
+    pub recipient_yield: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PartialFillEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub amount: BigUint<Api>,
+    pub price: BigUint<Api>,
+    pub remaining_amount: BigUint<Api>,
+}
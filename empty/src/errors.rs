@@ -0,0 +1,123 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+// Hata kataloğunu tek bir tablodan üretir
+// Her satırdan mesaj sabiti (ERR_*), EscrowError varyantı, ALL listesindeki yeri ve message() eşlemesi oluşur
+// Böylece üç liste birbirinden ayrı düşemez
+macro_rules! error_catalogue {
+    ($($variant:ident => $name:ident = $message:literal),* $(,)?) => {
+        $(pub const $name: &str = $message;)*
+
+        // Hata kodlarının ABI'deki karşılığı
+        // Hata kodu varyantın sırasından gelir (ilk varyant E001)
+        // Enum ABI'de yer aldığı için istemciler kod -> mesaj eşlemesini ABI ve getErrorCatalogue üzerinden okuyabilir
+        #[type_abi]
+        #[derive(
+            TopEncode,
+            TopDecode,
+            NestedEncode,
+            NestedDecode,
+            PartialEq,
+            Clone,
+            Copy
+        )]
+        pub enum EscrowError {
+            $($variant),*
+        }
+
+        impl EscrowError {
+            // Katalogdaki tüm hatalar, kod sırasıyla
+            pub const ALL: &'static [EscrowError] = &[$(EscrowError::$variant),*];
+
+            // İşlem geri alınırken dönen mesaj
+            pub const fn message(self) -> &'static str {
+                match self {
+                    $(EscrowError::$variant => $name),*
+                }
+            }
+        }
+    };
+}
+
+// Kontratın tüm hata mesajları - merkezi hata kataloğu
+// Tüm endpoint'ler require! içinde bu sabitleri kullanır, böylece aynı hata her yerde aynı mesajı döner
+// Her mesaj sabit bir hata kodu ile başlar, istemciler İngilizce metin yerine bu kodu kullanmalıdır
+// Yeni hatalar sadece tablonun sonuna eklenmelidir, mevcut kodlar ve mesajlar değiştirilmemelidir
+error_catalogue! {
+    PaymentZero => ERR_PAYMENT_ZERO = "E001: Must pay more than 0",
+    OfferNotFound => ERR_OFFER_NOT_FOUND = "E002: Offer not found",
+    OfferNotActive => ERR_OFFER_NOT_ACTIVE = "E003: Offer not active",
+    NotOfferCreator => ERR_NOT_OFFER_CREATOR = "E004: Not offer creator",
+    NotOfferRecipient => ERR_NOT_OFFER_RECIPIENT = "E005: Not offer recipient",
+    MemoTooLong => ERR_MEMO_TOO_LONG = "E006: Memo too long",
+    ReferenceTooLong => ERR_REFERENCE_TOO_LONG = "E007: Reference too long",
+    TermsHashTooLong => ERR_TERMS_HASH_TOO_LONG = "E008: Terms hash too long",
+    ReferenceAlreadyUsed => ERR_REFERENCE_ALREADY_USED = "E009: Reference already used",
+    CreatorBlocked => ERR_CREATOR_BLOCKED = "E010: Creator address is blocked",
+    RecipientBlocked => ERR_RECIPIENT_BLOCKED = "E011: Recipient address is blocked",
+    AddressAlreadyBlocked => ERR_ADDRESS_ALREADY_BLOCKED = "E012: Address already blocked",
+    AddressNotBlocked => ERR_ADDRESS_NOT_BLOCKED = "E013: Address not blocked",
+    OfferHasNoBlockedParty => ERR_OFFER_HAS_NO_BLOCKED_PARTY = "E014: Offer has no blocked party",
+    MissingRole => ERR_MISSING_ROLE = "E015: Caller does not have the required role",
+    AddressAlreadyHasRole => ERR_ADDRESS_ALREADY_HAS_ROLE = "E016: Address already has role",
+    AddressDoesNotHaveRole => ERR_ADDRESS_DOES_NOT_HAVE_ROLE = "E017: Address does not have role",
+    AdminRoleAlreadyProposed => ERR_ADMIN_ROLE_ALREADY_PROPOSED = "E018: Admin role already proposed",
+    NoPendingAdminRole => ERR_NO_PENDING_ADMIN_ROLE = "E019: No pending admin role",
+    MigrationBatchTooLarge => ERR_MIGRATION_BATCH_TOO_LARGE = "E020: Migration batch too large",
+    OfferNotFinished => ERR_OFFER_NOT_FINISHED = "E021: Offer not finished",
+    RetentionPeriodNotElapsed => ERR_RETENTION_PERIOD_NOT_ELAPSED = "E022: Retention period not elapsed",
+    ArchiveBatchTooLarge => ERR_ARCHIVE_BATCH_TOO_LARGE = "E023: Archive batch too large",
+    PageSizeTooLarge => ERR_PAGE_SIZE_TOO_LARGE = "E024: Page size too large",
+    RecipientReputationTooLow => ERR_RECIPIENT_REPUTATION_TOO_LOW = "E025: Recipient has too few completed trades",
+    RecipientNotContract => ERR_RECIPIENT_NOT_CONTRACT = "E026: Recipient is not a smart contract",
+    DeliveryEndpointEmpty => ERR_DELIVERY_ENDPOINT_EMPTY = "E027: Delivery endpoint is empty",
+    OfferNotPendingDelivery => ERR_OFFER_NOT_PENDING_DELIVERY = "E028: Offer not pending delivery",
+    DeliveryInProgress => ERR_DELIVERY_IN_PROGRESS = "E029: Delivery already in progress",
+    NotOfferParty => ERR_NOT_OFFER_PARTY = "E030: Not offer creator or recipient",
+    InvalidReleaseCondition => ERR_INVALID_RELEASE_CONDITION = "E031: Invalid release condition",
+    NoReleaseCondition => ERR_NO_RELEASE_CONDITION = "E032: Offer has no release condition",
+    ReleaseConditionNotMet => ERR_RELEASE_CONDITION_NOT_MET = "E033: Release condition not met",
+    PriceOracleNotSet => ERR_PRICE_ORACLE_NOT_SET = "E034: Price oracle not set",
+    InvalidPriceOracle => ERR_INVALID_PRICE_ORACLE = "E035: Price oracle must be a contract in the same shard",
+    InvalidOraclePrice => ERR_INVALID_ORACLE_PRICE = "E036: Oracle price is zero or stale",
+    InsufficientCollateral => ERR_INSUFFICIENT_COLLATERAL = "E037: Locked amount does not cover the USD amount",
+    SwapRouteNotSet => ERR_SWAP_ROUTE_NOT_SET = "E038: No swap route for token",
+    SwapWithDeliveryCall => ERR_SWAP_WITH_DELIVERY_CALL = "E039: Swap not supported with delivery call",
+    SwapOutputTooLow => ERR_SWAP_OUTPUT_TOO_LOW = "E040: Swap output below minimum",
+    InvalidSwapContract => ERR_INVALID_SWAP_CONTRACT = "E041: Swap contract must be a contract in the same shard",
+    LiquidStakingNotSet => ERR_LIQUID_STAKING_NOT_SET = "E042: Liquid staking contract not set",
+    InvalidLiquidStaking => ERR_INVALID_LIQUID_STAKING = "E043: Liquid staking must be a contract in the same shard",
+    InvalidYieldShare => ERR_INVALID_YIELD_SHARE = "E044: Yield share exceeds 10000 basis points",
+    NotSupportedForStakedOffer => ERR_NOT_SUPPORTED_FOR_STAKED_OFFER = "E045: Not supported for staked offers",
+    InvalidAuction => ERR_INVALID_AUCTION = "E046: Invalid auction parameters",
+    NotSupportedForAuction => ERR_NOT_SUPPORTED_FOR_AUCTION = "E047: Not supported for auction offers",
+    WrongAuctionKind => ERR_WRONG_AUCTION_KIND = "E048: Offer is not an auction of this kind",
+    AuctionEnded => ERR_AUCTION_ENDED = "E049: Auction has ended",
+    AuctionNotEnded => ERR_AUCTION_NOT_ENDED = "E050: Auction has not ended",
+    BidTooLow => ERR_BID_TOO_LOW = "E051: Bid too low",
+    AuctionHasBids => ERR_AUCTION_HAS_BIDS = "E052: Auction has bids",
+    CreatorCannotBid => ERR_CREATOR_CANNOT_BID = "E053: Creator cannot bid on own auction",
+    BidAlreadyCommitted => ERR_BID_ALREADY_COMMITTED = "E054: Bid already committed",
+    TooManySealedBids => ERR_TOO_MANY_SEALED_BIDS = "E055: Too many sealed bids",
+    RevealNotOpen => ERR_REVEAL_NOT_OPEN = "E056: Reveal window not open",
+    NoSealedBid => ERR_NO_SEALED_BID = "E057: No sealed bid to reveal",
+    BidHashMismatch => ERR_BID_HASH_MISMATCH = "E058: Bid does not match commitment",
+    BidExceedsDeposit => ERR_BID_EXCEEDS_DEPOSIT = "E059: Bid exceeds deposit",
+    InvalidPenalty => ERR_INVALID_PENALTY = "E060: Penalty exceeds 10000 basis points",
+    InvalidSellOffer => ERR_INVALID_SELL_OFFER = "E061: Invalid sell offer parameters",
+    NotSellOffer => ERR_NOT_SELL_OFFER = "E062: Offer is not a sell offer",
+    NotSupportedForSellOffer => ERR_NOT_SUPPORTED_FOR_SELL_OFFER = "E063: Not supported for sell offers",
+    CreatorCannotFill => ERR_CREATOR_CANNOT_FILL = "E064: Creator cannot fill own offer",
+    InvalidFillAmount => ERR_INVALID_FILL_AMOUNT = "E065: Fill amount is zero or exceeds remaining amount",
+    FillTooSmall => ERR_FILL_TOO_SMALL = "E066: Fill below minimum fill size",
+    FillPaymentTooLow => ERR_FILL_PAYMENT_TOO_LOW = "E067: Payment below fill price",
+    SelfOffer => ERR_SELF_OFFER = "E068: Creator cannot be the recipient",
+    NoPendingRefund => ERR_NO_PENDING_REFUND = "E069: No refund to withdraw",
+    InvalidMaxPriceAge => ERR_INVALID_MAX_PRICE_AGE = "E070: Max price age must be between 1 second and 1 day",
+    TokenNotAllowed => ERR_TOKEN_NOT_ALLOWED = "E071: Token not allowed",
+    TokenAlreadyAllowed => ERR_TOKEN_ALREADY_ALLOWED = "E072: Token already allowed",
+    InvalidTokenLimits => ERR_INVALID_TOKEN_LIMITS = "E073: Invalid token limits",
+    TokenLimitsNotSet => ERR_TOKEN_LIMITS_NOT_SET = "E074: Token limits not set",
+    AmountBelowTokenMin => ERR_AMOUNT_BELOW_TOKEN_MIN = "E075: Amount below token minimum",
+    AmountAboveTokenMax => ERR_AMOUNT_ABOVE_TOKEN_MAX = "E076: Amount above token maximum"
+}
@@ -0,0 +1,2972 @@
+use empty::empty_proxy::{self, AuctionKind, EscrowContractProxy, OfferStatus, ReleaseCondition, Role};
+use empty::Offer;
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::scenario::model::{BytesKey, CheckStorage};
+
+const CODE_PATH: MxscPath = MxscPath::new("output/empty.mxsc.json");
+const MARKETPLACE_CODE_PATH: MxscPath = MxscPath::new("mocks/recipient-mock/output/recipient-mock.mxsc.json");
+const DAO_CODE_PATH: MxscPath = MxscPath::new("mocks/dao-mock/output/dao-mock.mxsc.json");
+const PRICE_AGGREGATOR_CODE_PATH: MxscPath =
+    MxscPath::new("mocks/price-aggregator-mock/output/price-aggregator-mock.mxsc.json");
+const WRAPPER_CODE_PATH: MxscPath = MxscPath::new("mocks/egld-wrapper-mock/output/egld-wrapper-mock.mxsc.json");
+const PAIR_CODE_PATH: MxscPath = MxscPath::new("mocks/pair-mock/output/pair-mock.mxsc.json");
+const LIQUID_STAKING_CODE_PATH: MxscPath =
+    MxscPath::new("mocks/liquid-staking-mock/output/liquid-staking-mock.mxsc.json");
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const SELLER: TestAddress = TestAddress::new("seller");
+const BUYER: TestAddress = TestAddress::new("buyer");
+const OTHER: TestAddress = TestAddress::new("other");
+const ESCROW: TestSCAddress = TestSCAddress::new("escrow");
+const MARKETPLACE: TestSCAddress = TestSCAddress::new("marketplace");
+const DAO: TestSCAddress = TestSCAddress::new("dao");
+const PRICE_AGGREGATOR: TestSCAddress = TestSCAddress::new("price-aggregator");
+const WRAPPER: TestSCAddress = TestSCAddress::new("egld-wrapper");
+const PAIR: TestSCAddress = TestSCAddress::new("pair");
+const LIQUID_STAKING: TestSCAddress = TestSCAddress::new("liquid-staking");
+
+const WEGLD: TestTokenIdentifier = TestTokenIdentifier::new("WEGLD-abcdef");
+const USDC: TestTokenIdentifier = TestTokenIdentifier::new("USDC-abcdef");
+const SEGLD: TestTokenIdentifier = TestTokenIdentifier::new("SEGLD-abcdef");
+const NFT: TestTokenIdentifier = TestTokenIdentifier::new("NFT-abcdef");
+const TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("TOKEN-abcdef");
+
+const START_BALANCE: u64 = 1_000;
+const START_TIMESTAMP: u64 = 1_000;
+const DAY: u64 = 24 * 60 * 60;
+const EGLD: u64 = 1_000_000_000_000_000_000;
+const MAX_PRICE_AGE: u64 = 60 * 60;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(CODE_PATH, empty::ContractBuilder);
+    blockchain.register_contract(MARKETPLACE_CODE_PATH, recipient_mock::ContractBuilder);
+    blockchain.register_contract(DAO_CODE_PATH, dao_mock::ContractBuilder);
+    blockchain.register_contract(PRICE_AGGREGATOR_CODE_PATH, price_aggregator_mock::ContractBuilder);
+    blockchain.register_contract(WRAPPER_CODE_PATH, egld_wrapper_mock::ContractBuilder);
+    blockchain.register_contract(PAIR_CODE_PATH, pair_mock::ContractBuilder);
+    blockchain.register_contract(LIQUID_STAKING_CODE_PATH, liquid_staking_mock::ContractBuilder);
+    blockchain
+}
+
+// Her test için kontratı deploy eder ve taraflara bakiye verir
+struct EscrowTestState {
+    world: ScenarioWorld,
+}
+
+impl EscrowTestState {
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER).nonce(1).balance(START_BALANCE);
+        world.account(SELLER).nonce(1).balance(START_BALANCE);
+        world.account(BUYER).nonce(1).balance(START_BALANCE);
+        world.account(OTHER).nonce(1).balance(START_BALANCE);
+        world.current_block().block_timestamp(START_TIMESTAMP);
+
+        world
+            .tx()
+            .from(OWNER)
+            .typed(EscrowContractProxy)
+            .init()
+            .code(CODE_PATH)
+            .new_address(ESCROW)
+            .run();
+
+        Self { world }
+    }
+
+    fn create(&mut self, from: TestAddress, to: impl ProxyArg<ManagedAddress<StaticApi>>, amount: u64) -> u64 {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                to,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                OptionalValue::<u64>::None,
+                OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .egld(amount)
+            .run();
+
+        self.last_offer_id()
+    }
+
+    fn create_with_reference(&mut self, from: TestAddress, to: TestAddress, amount: u64, reference: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                to,
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::from("memo")),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::from(reference)),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::from("hash")),
+                OptionalValue::<u64>::None,
+                OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .egld(amount)
+            .run();
+    }
+
+    fn create_expect_err(&mut self, from: TestAddress, to: TestAddress, amount: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                to,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                OptionalValue::<u64>::None,
+                OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .egld(amount)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn accept(&mut self, from: TestAddress, offer_id: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .accept_offer(offer_id, no_swap())
+            .run();
+    }
+
+    fn accept_expect_err(&mut self, from: TestAddress, offer_id: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .accept_offer(offer_id, no_swap())
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn cancel(&mut self, from: TestAddress, offer_id: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .cancel_offer(offer_id)
+            .run();
+    }
+
+    fn cancel_expect_err(&mut self, from: TestAddress, offer_id: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .cancel_offer(offer_id)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn grant_role(&mut self, from: TestAddress, role: Role, address: TestAddress) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .grant_role(role, address)
+            .run();
+    }
+
+    fn block(&mut self, from: TestAddress, address: TestAddress) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .block_address(address)
+            .run();
+    }
+
+    fn allow_token(&mut self, token: TestTokenIdentifier) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .allow_token(token)
+            .run();
+    }
+
+    fn archive(&mut self, offer_ids: &[u64]) {
+        self.world
+            .tx()
+            .from(OTHER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .archive_offers(MultiValueEncoded::from_iter(offer_ids.iter().copied()))
+            .run();
+    }
+
+    fn archive_expect_err(&mut self, offer_ids: &[u64], err: &str) {
+        self.world
+            .tx()
+            .from(OTHER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .archive_offers(MultiValueEncoded::from_iter(offer_ids.iter().copied()))
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    // Teslimat çağrılarını alan marketplace taklidini deploy eder
+    fn deploy_marketplace(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .raw_deploy()
+            .code(MARKETPLACE_CODE_PATH)
+            .new_address(MARKETPLACE)
+            .run();
+    }
+
+    fn set_marketplace_rejecting(&mut self, rejecting: bool) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(MARKETPLACE)
+            .raw_call("setRejecting")
+            .argument(&rejecting)
+            .run();
+    }
+
+    fn marketplace_received(&mut self, order_id: u64) -> u64 {
+        let raw = self
+            .world
+            .query()
+            .to(MARKETPLACE)
+            .raw_call("getReceived")
+            .argument(&order_id)
+            .returns(ReturnsRawResult)
+            .run();
+
+        u64::top_decode(raw.get(0).clone()).unwrap()
+    }
+
+    fn set_delivery_call(&mut self, from: TestAddress, offer_id: u64, order_id: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .set_delivery_call(
+                offer_id,
+                5_000_000u64,
+                "onEscrowDelivery",
+                MultiValueVec::from(vec![top_encode_to_vec_u8_or_panic(&order_id)]),
+            )
+            .run();
+    }
+
+    fn release(&mut self, from: TestAddress, offer_id: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .release_offer(offer_id)
+            .run();
+    }
+
+    fn retry_delivery(&mut self, from: TestAddress, offer_id: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .retry_delivery(offer_id)
+            .run();
+    }
+
+    fn retry_delivery_expect_err(&mut self, from: TestAddress, offer_id: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .retry_delivery(offer_id)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    // Serbest bırakma koşullarında kullanılan DAO taklidini deploy eder
+    fn deploy_dao(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .raw_deploy()
+            .code(DAO_CODE_PATH)
+            .new_address(DAO)
+            .run();
+    }
+
+    fn set_proposal_passed(&mut self, proposal_id: u64, passed: bool) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(DAO)
+            .raw_call("setProposalPassed")
+            .argument(&proposal_id)
+            .argument(&passed)
+            .run();
+    }
+
+    fn create_conditional(
+        &mut self,
+        from: TestAddress,
+        to: TestAddress,
+        amount: u64,
+        condition: ReleaseCondition<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                to,
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(0u64),
+                OptionalValue::Some(Some(condition)),
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .egld(amount)
+            .run();
+    }
+
+    fn try_release_expect_err(&mut self, from: TestAddress, offer_id: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .try_release(offer_id)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    // Fiyat aggregator taklidini deploy eder ve escrow'un fiyat oracle'ı olarak ayarlar
+    fn deploy_price_aggregator(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .raw_deploy()
+            .code(PRICE_AGGREGATOR_CODE_PATH)
+            .new_address(PRICE_AGGREGATOR)
+            .run();
+
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .set_price_oracle(PRICE_AGGREGATOR, MAX_PRICE_AGE)
+            .run();
+    }
+
+    // EGLD fiyatını 2 ondalık basamakla (sent) yazar
+    fn set_egld_price(&mut self, usd_cents: u64) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(PRICE_AGGREGATOR)
+            .raw_call("setLatestPriceFeed")
+            .argument(&"EGLD")
+            .argument(&"USD")
+            .argument(&BigUint::<StaticApi>::from(usd_cents))
+            .argument(&2u8)
+            .run();
+    }
+
+    fn create_usd(&mut self, from: TestAddress, to: TestAddress, amount: u64, usd_cents: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                to,
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(0u64),
+                OptionalValue::Some(None::<ReleaseCondition<StaticApi>>),
+                OptionalValue::Some(BigUint::<StaticApi>::from(usd_cents)),
+                OptionalValue::<bool>::None,
+            )
+            .egld(amount)
+            .run();
+    }
+
+    // EGLD -> WEGLD -> USDC takas yolunu kurar, pair 1 WEGLD için 3 USDC verir
+    // Mock kontratlar ödeyecekleri tokenlarla birlikte hesap durumu olarak oluşturulur
+    fn setup_swap_route(&mut self) {
+        self.world
+            .account(WRAPPER)
+            .code(WRAPPER_CODE_PATH)
+            .owner(OWNER)
+            .esdt_balance(WEGLD, 1_000);
+        self.world
+            .account(PAIR)
+            .code(PAIR_CODE_PATH)
+            .owner(OWNER)
+            .esdt_balance(USDC, 10_000);
+
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(WRAPPER)
+            .raw_call("setWrappedEgldToken")
+            .argument(&WEGLD)
+            .run();
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(PAIR)
+            .raw_call("setExchangeRate")
+            .argument(&3u64)
+            .argument(&1u64)
+            .run();
+
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .set_egld_wrapper(WRAPPER)
+            .run();
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .set_swap_pair(USDC, PAIR)
+            .run();
+    }
+
+    fn accept_with_swap_expect_err(
+        &mut self,
+        from: TestAddress,
+        offer_id: u64,
+        token_out: TestTokenIdentifier,
+        amount_out_min: u64,
+        err: &str,
+    ) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .accept_offer(offer_id, swap_to(token_out, amount_out_min))
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    // Likit staking kontratını kurar, başlangıçta 1 LST = 1 EGLD
+    // Mock kontrat ödeyeceği LST ile birlikte hesap durumu olarak oluşturulur
+    fn setup_liquid_staking(&mut self, recipient_yield_share: u32) {
+        self.world
+            .account(LIQUID_STAKING)
+            .code(LIQUID_STAKING_CODE_PATH)
+            .owner(OWNER)
+            .esdt_balance(SEGLD, 1_000);
+
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(LIQUID_STAKING)
+            .raw_call("setLsToken")
+            .argument(&SEGLD)
+            .run();
+        self.set_ls_exchange_rate(1, 1);
+
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .set_liquid_staking(LIQUID_STAKING, recipient_yield_share)
+            .run();
+    }
+
+    // Bir LST'nin EGLD değerini numerator / denominator olarak ayarlar
+    fn set_ls_exchange_rate(&mut self, numerator: u64, denominator: u64) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(LIQUID_STAKING)
+            .raw_call("setExchangeRate")
+            .argument(&numerator)
+            .argument(&denominator)
+            .run();
+    }
+
+    fn create_staked(&mut self, from: TestAddress, to: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                to,
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(0u64),
+                OptionalValue::Some(None::<ReleaseCondition<StaticApi>>),
+                OptionalValue::Some(BigUint::<StaticApi>::zero()),
+                OptionalValue::Some(true),
+            )
+            .egld(amount)
+            .run();
+    }
+
+    fn bid(&mut self, from: TestAddress, offer_id: u64, amount: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .bid(offer_id)
+            .egld(amount)
+            .run();
+    }
+
+    fn pending_refund(&mut self, address: TestAddress) -> u64 {
+        self.world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .pending_refunds(address)
+            .returns(ReturnsResult)
+            .run()
+            .to_u64()
+            .unwrap()
+    }
+
+    fn withdraw_refund(&mut self, from: TestAddress) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .withdraw_refund()
+            .run();
+    }
+
+    fn withdraw_refund_expect_err(&mut self, from: TestAddress, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .withdraw_refund()
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn bid_expect_err(&mut self, from: TestAddress, offer_id: u64, amount: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .bid(offer_id)
+            .egld(amount)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn buy_expect_err(&mut self, from: TestAddress, offer_id: u64, amount: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .buy(offer_id)
+            .egld(amount)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn settle_auction_expect_err(&mut self, offer_id: u64, err: &str) {
+        self.world
+            .tx()
+            .from(OTHER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .settle_auction(offer_id)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn bid_hash(&mut self, bidder: TestAddress, amount: u64, salt: &str) -> ManagedByteArray<StaticApi, 32> {
+        self.world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .compute_bid_hash(bidder, amount, ManagedBuffer::from(salt))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn commit_bid(&mut self, from: TestAddress, offer_id: u64, amount: u64, salt: &str, deposit: u64) {
+        let bid_hash = self.bid_hash(from, amount, salt);
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .commit_bid(offer_id, bid_hash)
+            .egld(deposit)
+            .run();
+    }
+
+    fn commit_bid_expect_err(&mut self, from: TestAddress, offer_id: u64, deposit: u64, err: &str) {
+        let bid_hash = self.bid_hash(from, deposit, "salt");
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .commit_bid(offer_id, bid_hash)
+            .egld(deposit)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn reveal_bid(&mut self, from: TestAddress, offer_id: u64, amount: u64, salt: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .reveal_bid(offer_id, amount, ManagedBuffer::from(salt))
+            .run();
+    }
+
+    fn reveal_bid_expect_err(&mut self, from: TestAddress, offer_id: u64, amount: u64, salt: &str, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .reveal_bid(offer_id, amount, ManagedBuffer::from(salt))
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn create_sell_offer(&mut self, from: TestAddress, amount: u64, price: u64, min_fill: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create_sell_offer(price, min_fill)
+            .single_esdt(&TOKEN.to_token_identifier(), 0, &BigUint::from(amount))
+            .run();
+    }
+
+    fn accept_partial(&mut self, from: TestAddress, offer_id: u64, amount: u64, payment: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .accept_partial(offer_id, amount)
+            .egld(payment)
+            .run();
+    }
+
+    fn accept_partial_expect_err(&mut self, from: TestAddress, offer_id: u64, amount: u64, payment: u64, err: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .accept_partial(offer_id, amount)
+            .egld(payment)
+            .returns(ExpectError(4, err))
+            .run();
+    }
+
+    fn last_offer_id(&mut self) -> u64 {
+        self.world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .last_offer_id()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    // Offer'ın TopDecode'u elle yazıldığı için proxy'deki tip yerine kontratın kendi tipiyle çözülür
+    fn offer(&mut self, offer_id: u64) -> Option<Offer<StaticApi>> {
+        let raw = self
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .get_offer(offer_id)
+            .returns(ReturnsRawResult)
+            .run();
+
+        decode_offers(raw).pop()
+    }
+
+    // getStats içinden EGLD için kilitli değeri döndürür
+    fn egld_value_locked(&mut self) -> BigUint<StaticApi> {
+        let stats = self
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .get_stats()
+            .returns(ReturnsResult)
+            .run();
+
+        stats
+            .tokens
+            .iter()
+            .find(|token_stats| token_stats.token.is_egld())
+            .map(|token_stats| token_stats.value_locked.clone())
+            .unwrap_or_default()
+    }
+
+    fn user_closed_offer_ids(&mut self, user: TestAddress, offset: usize, limit: usize) -> Vec<u64> {
+        let raw = self
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .get_user_closed_offers(user, offset, limit)
+            .returns(ReturnsRawResult)
+            .run();
+
+        decode_offers(raw).iter().map(|offer| offer.offer_id).collect()
+    }
+
+    fn is_blocked(&mut self, address: TestAddress) -> bool {
+        self.world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .is_address_blocked(address)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn has_role(&mut self, role: Role, address: TestAddress) -> bool {
+        self.world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .has_role(role, address)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn check_balance(&mut self, address: TestAddress, balance: u64) {
+        self.world.check_account(address).balance(balance);
+    }
+}
+
+fn decode_offers(raw: ManagedVec<StaticApi, ManagedBuffer<StaticApi>>) -> Vec<Offer<StaticApi>> {
+    raw.iter()
+        .map(|buffer| Offer::top_decode(buffer.clone()).unwrap())
+        .collect()
+}
+
+// Ödeme EGLD olarak alınır
+fn no_swap() -> OptionalValue<MultiValue2<TokenIdentifier<StaticApi>, BigUint<StaticApi>>> {
+    OptionalValue::None
+}
+
+// Ödeme token_out'a takas edilir, en az amount_out_min alınmalıdır
+fn swap_to(
+    token_out: TestTokenIdentifier,
+    amount_out_min: u64,
+) -> OptionalValue<MultiValue2<TokenIdentifier<StaticApi>, BigUint<StaticApi>>> {
+    OptionalValue::Some((token_out.to_token_identifier(), BigUint::from(amount_out_min)).into())
+}
+
+// Fonlar ancak DAO'daki öneri kabul edildiğinde serbest bırakılabilir
+fn proposal_condition(target: ManagedAddress<StaticApi>, proposal_id: u64) -> ReleaseCondition<StaticApi> {
+    let mut arguments = ManagedVec::new();
+    arguments.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&proposal_id)));
+
+    ReleaseCondition {
+        target,
+        view: ManagedBuffer::from("isProposalPassed"),
+        arguments,
+        expected: ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&true)),
+    }
+}
+
+#[test]
+fn deploy_test() {
+    let mut state = EscrowTestState::new();
+
+    assert_eq!(state.last_offer_id(), 0);
+    state.world.check_account(ESCROW).balance(0);
+}
+
+#[test]
+fn create_offer_test() {
+    let mut state = EscrowTestState::new();
+
+    let logs = state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create(
+            BUYER,
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<u64>::None,
+            OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<bool>::None,
+        )
+        .egld(100)
+        .returns(ReturnsLogs)
+        .run();
+
+    assert_eq!(state.last_offer_id(), 1);
+    state.check_balance(SELLER, START_BALANCE - 100);
+    state.world.check_account(ESCROW).balance(100);
+
+    let offer = state.offer(1).unwrap();
+    assert_eq!(offer.version, empty::OFFER_VERSION);
+    assert_eq!(offer.creator, SELLER.to_managed_address());
+    assert_eq!(offer.recipient, BUYER.to_managed_address());
+    assert_eq!(offer.amount, BigUint::from(100u64));
+    assert!(offer.status == empty::OfferStatus::Active);
+    assert_eq!(offer.created_timestamp, START_TIMESTAMP);
+    assert_eq!(offer.closed_timestamp, 0);
+
+    // Teklif ID, oluşturan ve alıcı topic'lerde taşınır
+    let log = logs.iter().find(|log| log.topics[0] == b"createOffer").unwrap();
+    assert_eq!(log.topics[1], top_encode_to_vec_u8_or_panic(&1u64));
+    assert_eq!(log.topics[2], SELLER.to_address().as_bytes());
+    assert_eq!(log.topics[3], BUYER.to_address().as_bytes());
+    assert_eq!(log.topics.len(), 4);
+    assert_eq!(log.data.len(), 1);
+
+    let user_offers = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .user_offers(SELLER)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(user_offers.into_iter().collect::<Vec<u64>>(), vec![1u64]);
+}
+
+#[test]
+fn create_offer_fail_test() {
+    let mut state = EscrowTestState::new();
+
+    state.create_expect_err(SELLER, BUYER, 0, "E001: Must pay more than 0");
+
+    let long_memo = "a".repeat(empty::MAX_MEMO_LENGTH + 1);
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create(
+            BUYER,
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::from(long_memo.as_str())),
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<u64>::None,
+            OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<bool>::None,
+        )
+        .egld(100)
+        .returns(ExpectError(4, "E006: Memo too long"))
+        .run();
+
+    // İsteğe bağlı argümanlar sırayla okunur, sonraki bir argüman verilecekse öncekiler boş buffer olarak gönderilir
+    let long_reference = "r".repeat(empty::MAX_REFERENCE_LENGTH + 1);
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create(
+            BUYER,
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::from(long_reference.as_str())),
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<u64>::None,
+            OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<bool>::None,
+        )
+        .egld(100)
+        .returns(ExpectError(4, "E007: Reference too long"))
+        .run();
+
+    let long_terms_hash = "h".repeat(empty::MAX_TERMS_HASH_LENGTH + 1);
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create(
+            BUYER,
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::from(long_terms_hash.as_str())),
+            OptionalValue::<u64>::None,
+            OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<bool>::None,
+        )
+        .egld(100)
+        .returns(ExpectError(4, "E008: Terms hash too long"))
+        .run();
+
+    // Başarısız çağrılar hiçbir şey kilitlemez
+    assert_eq!(state.last_offer_id(), 0);
+    state.check_balance(SELLER, START_BALANCE);
+}
+
+#[test]
+fn create_offer_reference_test() {
+    let mut state = EscrowTestState::new();
+
+    state.create_with_reference(SELLER, BUYER, 100, "invoice-1");
+
+    let raw = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_offer_by_reference(SELLER, ManagedBuffer::from("invoice-1"))
+        .returns(ReturnsRawResult)
+        .run();
+    let offer = decode_offers(raw).pop().unwrap();
+    assert_eq!(offer.offer_id, 1);
+    assert_eq!(offer.metadata.memo, ManagedBuffer::from("memo"));
+    assert_eq!(offer.metadata.terms_hash, ManagedBuffer::from("hash"));
+
+    // Aynı referansla tekrar deneme fonları ikinci kez kilitlemez
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create(
+            BUYER,
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::from("invoice-1")),
+            OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            OptionalValue::<u64>::None,
+            OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<bool>::None,
+        )
+        .egld(100)
+        .returns(ExpectError(4, "E009: Reference already used"))
+        .run();
+
+    // Referans oluşturan başına benzersizdir
+    state.create_with_reference(OTHER, BUYER, 100, "invoice-1");
+    assert_eq!(state.last_offer_id(), 2);
+}
+
+#[test]
+fn accept_offer_test() {
+    let mut state = EscrowTestState::new();
+
+    let offer_id = state.create(SELLER, BUYER, 100);
+
+    state.accept_expect_err(OTHER, offer_id, "E005: Not offer recipient");
+    state.accept_expect_err(BUYER, 99, "E002: Offer not found");
+
+    state.world.current_block().block_timestamp(START_TIMESTAMP + 10);
+    let logs = state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_offer(offer_id, no_swap())
+        .returns(ReturnsLogs)
+        .run();
+
+    state.check_balance(SELLER, START_BALANCE - 100);
+    state.check_balance(BUYER, START_BALANCE + 100);
+    state.world.check_account(ESCROW).balance(0);
+
+    let offer = state.offer(offer_id).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    assert_eq!(offer.closed_timestamp, START_TIMESTAMP + 10);
+    assert_eq!(offer.closed_by, BUYER.to_managed_address());
+
+    let log = logs.iter().find(|log| log.topics[0] == b"acceptOffer").unwrap();
+    assert_eq!(log.topics[2], SELLER.to_address().as_bytes());
+    assert_eq!(log.topics[3], BUYER.to_address().as_bytes());
+
+    let data = empty_proxy::OfferEventData::<StaticApi>::top_decode(log.data[0].clone()).unwrap();
+    assert_eq!(data.payment.amount, BigUint::from(100u64));
+    assert!(data.old_status == Some(OfferStatus::Active));
+    assert!(data.new_status == OfferStatus::Completed);
+    assert_eq!(data.timestamp, START_TIMESTAMP + 10);
+
+    state.accept_expect_err(BUYER, offer_id, "E003: Offer not active");
+    state.cancel_expect_err(SELLER, offer_id, "E003: Offer not active");
+}
+
+#[test]
+fn cancel_offer_test() {
+    let mut state = EscrowTestState::new();
+
+    let offer_id = state.create(SELLER, BUYER, 100);
+
+    state.cancel_expect_err(BUYER, offer_id, "E004: Not offer creator");
+    state.cancel_expect_err(SELLER, 99, "E002: Offer not found");
+
+    let logs = state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .cancel_offer(offer_id)
+        .returns(ReturnsLogs)
+        .run();
+
+    state.check_balance(SELLER, START_BALANCE);
+    state.check_balance(BUYER, START_BALANCE);
+    state.world.check_account(ESCROW).balance(0);
+
+    let offer = state.offer(offer_id).unwrap();
+    assert!(offer.status == empty::OfferStatus::Cancelled);
+    assert_eq!(offer.closed_by, SELLER.to_managed_address());
+
+    let log = logs.iter().find(|log| log.topics[0] == b"cancelOffer").unwrap();
+    let data = empty_proxy::OfferEventData::<StaticApi>::top_decode(log.data[0].clone()).unwrap();
+    assert!(data.new_status == OfferStatus::Cancelled);
+
+    state.cancel_expect_err(SELLER, offer_id, "E003: Offer not active");
+    state.accept_expect_err(BUYER, offer_id, "E003: Offer not active");
+}
+
+#[test]
+fn offer_views_test() {
+    let mut state = EscrowTestState::new();
+
+    let first = state.create(SELLER, BUYER, 100);
+    let second = state.create(SELLER, BUYER, 200);
+    let third = state.create(SELLER, OTHER, 300);
+    state.accept(BUYER, first);
+    state.cancel(SELLER, second);
+
+    let raw = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_active_offers()
+        .returns(ReturnsRawResult)
+        .run();
+    let active: Vec<u64> = decode_offers(raw).iter().map(|offer| offer.offer_id).collect();
+    assert_eq!(active, vec![third]);
+
+    let raw = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_user_incoming_active_offers(OTHER)
+        .returns(ReturnsRawResult)
+        .run();
+    assert_eq!(decode_offers(raw).len(), 1);
+
+    // Kapanmış teklifler en yeniden eskiye sayfalanır
+    assert_eq!(state.user_closed_offer_ids(SELLER, 0, 10), vec![second, first]);
+    assert_eq!(state.user_closed_offer_ids(SELLER, 1, 10), vec![first]);
+
+    state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_user_closed_offers(SELLER, 0usize, empty::MAX_PAGE_SIZE + 1)
+        .returns(ExpectError(4, "E024: Page size too large"))
+        .run();
+
+    let stats = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_stats()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(stats.total_created, 3);
+    assert_eq!(stats.active_count, 1);
+    assert_eq!(stats.completed_count, 1);
+    assert_eq!(stats.cancelled_count, 1);
+    assert_eq!(stats.tokens.get(0).value_locked, BigUint::from(300u64));
+    assert_eq!(stats.tokens.get(0).volume, BigUint::from(100u64));
+
+    assert!(state.offer(99).is_none());
+
+    let catalogue = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_error_catalogue()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(catalogue.into_iter().count(), empty::errors::EscrowError::ALL.len());
+}
+
+#[test]
+fn error_catalogue_test() {
+    use empty::errors::*;
+
+    // Kodlar E001'den başlayarak boşluksuz ve tekrarsız sıralanır
+    for (index, error) in EscrowError::ALL.iter().enumerate() {
+        let code = format!("E{:03}:", index + 1);
+        assert!(error.message().starts_with(&code), "{} should start with {code}", error.message());
+    }
+
+    // Varyantlar kendi sabitlerinin mesajını döndürür
+    assert_eq!(EscrowError::PaymentZero.message(), ERR_PAYMENT_ZERO);
+    assert_eq!(EscrowError::MissingRole.message(), ERR_MISSING_ROLE);
+    assert_eq!(EscrowError::FillPaymentTooLow.message(), ERR_FILL_PAYMENT_TOO_LOW);
+
+    // View kataloğu aynı sırayla ve aynı mesajlarla döndürür
+    let mut state = EscrowTestState::new();
+    let catalogue = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_error_catalogue()
+        .returns(ReturnsResult)
+        .run();
+    let messages: Vec<String> = catalogue
+        .into_iter()
+        .map(|entry| entry.into_tuple().1.to_string())
+        .collect();
+    let expected: Vec<String> = EscrowError::ALL.iter().map(|error| error.message().to_string()).collect();
+    assert_eq!(messages, expected);
+}
+
+#[test]
+fn reputation_test() {
+    let mut state = EscrowTestState::new();
+
+    // Kendine teklif oluşturularak sayaçlar şişirilemez
+    state.create_expect_err(SELLER, SELLER, 100, "E068: Creator cannot be the recipient");
+
+    let first = state.create(SELLER, BUYER, 100);
+    let second = state.create(SELLER, BUYER, 100);
+    state.accept(BUYER, first);
+    state.cancel(SELLER, second);
+
+    let seller = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_user_reputation(SELLER)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(seller.offers_created, 2);
+    assert_eq!(seller.completed_as_creator, 1);
+    assert_eq!(seller.cancelled_as_creator, 1);
+
+    let buyer = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_user_reputation(BUYER)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(buyer.accepted_as_recipient, 1);
+
+    // OTHER hiç işlem tamamlamadığı için eşik karşılanmaz, BUYER ise karşılar
+    for (recipient, min_trades, err) in [
+        (OTHER, 1u64, Some("E025: Recipient has too few completed trades")),
+        (BUYER, 1u64, None),
+    ] {
+        let tx = state
+            .world
+            .tx()
+            .from(SELLER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                recipient,
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(min_trades),
+                OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .egld(100);
+        match err {
+            Some(err) => tx.returns(ExpectError(4, err)).run(),
+            None => tx.run(),
+        }
+    }
+    assert_eq!(state.last_offer_id(), 3);
+}
+
+#[test]
+fn role_management_test() {
+    let mut state = EscrowTestState::new();
+
+    // Owner dışındaki adresler rolsüz yetkili endpoint çağıramaz
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .grant_role(Role::Pauser, OTHER)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+
+    state.grant_role(OWNER, Role::Pauser, OTHER);
+    assert!(state.has_role(Role::Pauser, OTHER));
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .grant_role(Role::Pauser, OTHER)
+        .returns(ExpectError(4, "E016: Address already has role"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .revoke_role(Role::Pauser, OTHER)
+        .run();
+    assert!(!state.has_role(Role::Pauser, OTHER));
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .revoke_role(Role::Pauser, OTHER)
+        .returns(ExpectError(4, "E017: Address does not have role"))
+        .run();
+
+    // Admin rolü iki adımda devredilir
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_admin_role()
+        .returns(ExpectError(4, "E019: No pending admin role"))
+        .run();
+
+    state.grant_role(OWNER, Role::Admin, OTHER);
+    assert!(!state.has_role(Role::Admin, OTHER));
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .grant_role(Role::Admin, OTHER)
+        .returns(ExpectError(4, "E018: Admin role already proposed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_admin_role()
+        .run();
+    assert!(state.has_role(Role::Admin, OTHER));
+
+    // Yeni Admin yetkili endpoint'leri çağırabilir
+    state.grant_role(OTHER, Role::ComplianceOfficer, BUYER);
+    assert!(state.has_role(Role::ComplianceOfficer, BUYER));
+}
+
+#[test]
+fn compliance_test() {
+    let mut state = EscrowTestState::new();
+
+    let offer_id = state.create(SELLER, BUYER, 100);
+
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .block_address(BUYER)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .admin_refund_offer(offer_id)
+        .returns(ExpectError(4, "E014: Offer has no blocked party"))
+        .run();
+
+    state.grant_role(OWNER, Role::ComplianceOfficer, OTHER);
+    state.block(OTHER, BUYER);
+    assert!(state.is_blocked(BUYER));
+
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .block_address(BUYER)
+        .returns(ExpectError(4, "E012: Address already blocked"))
+        .run();
+
+    state.create_expect_err(SELLER, BUYER, 100, "E011: Recipient address is blocked");
+    state.create_expect_err(BUYER, SELLER, 100, "E010: Creator address is blocked");
+    state.accept_expect_err(BUYER, offer_id, "E011: Recipient address is blocked");
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .admin_refund_offer(offer_id)
+        .run();
+
+    state.check_balance(SELLER, START_BALANCE);
+    state.world.check_account(ESCROW).balance(0);
+    let offer = state.offer(offer_id).unwrap();
+    assert!(offer.status == empty::OfferStatus::Cancelled);
+    assert_eq!(offer.closed_by, OWNER.to_managed_address());
+
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .unblock_address(BUYER)
+        .run();
+    assert!(!state.is_blocked(BUYER));
+
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .unblock_address(BUYER)
+        .returns(ExpectError(4, "E013: Address not blocked"))
+        .run();
+}
+
+#[test]
+fn archive_test() {
+    let mut state = EscrowTestState::new();
+
+    let first = state.create(SELLER, BUYER, 100);
+    let second = state.create(SELLER, BUYER, 100);
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::English, 100u64, 0u64, DAY, OptionalValue::<u64>::None)
+        .egld(10)
+        .run();
+    let auction = state.last_offer_id();
+    state.cancel(SELLER, auction);
+
+    state.archive_expect_err(&[first], "E021: Offer not finished");
+
+    state.accept(BUYER, first);
+    state.archive_expect_err(&[first], "E022: Retention period not elapsed");
+
+    let too_many: Vec<u64> = (0..=empty::MAX_ARCHIVE_BATCH as u64).collect();
+    state.archive_expect_err(&too_many, "E023: Archive batch too large");
+
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_archive_retention_period(DAY)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_archive_retention_period(DAY)
+        .run();
+
+    state.world.current_block().block_timestamp(START_TIMESTAMP + DAY);
+    state.archive(&[first, auction]);
+
+    assert!(state.offer(first).is_none());
+
+    // Teklife bağlı diğer kayıtlar da temizlenir
+    assert!(state.offer(auction).is_none());
+    let archived_auction = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_auction(auction)
+        .returns(ReturnsResult)
+        .run();
+    assert!(archived_auction.into_option().is_none());
+    let summary = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .archived_offer(first)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(summary.offer_id, first);
+    assert!(summary.status == OfferStatus::Completed);
+    assert_eq!(summary.amount, BigUint::from(100u64));
+
+    // Arşivlenen teklif kullanıcı listelerinden de çıkar
+    assert!(state.user_closed_offer_ids(SELLER, 0, 10).is_empty());
+    assert!(state.offer(second).is_some());
+}
+
+#[test]
+fn migration_and_upgrade_test() {
+    let mut state = EscrowTestState::new();
+
+    state.create(SELLER, BUYER, 100);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .migrate_offers(1u64, empty::MAX_MIGRATION_BATCH + 1)
+        .returns(ExpectError(4, "E020: Migration batch too large"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .upgrade()
+        .code(CODE_PATH)
+        .run();
+
+    // Versiyonlu sürümle oluşturulan teklifler taşınacak kayıt sayılmaz
+    let (legacy_count, remaining) = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_migration_progress()
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(legacy_count, 0);
+    assert_eq!(remaining, 0);
+
+    let migrated = state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .migrate_offers(1u64, 10u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(migrated, 0);
+
+    // Upgrade mevcut teklifleri etkilemez
+    assert_eq!(state.offer(1).unwrap().amount, BigUint::from(100u64));
+
+    // İlk sürümün storage'ı: teklif versiyon byte'ı olmadan, sadece ilk altı alanla kaydedilmiştir
+    let legacy_escrow = TestSCAddress::new("legacy-escrow");
+    let legacy_offer_key = "str:offer|u64:1";
+    let legacy_offer = "u64:1|address:seller|address:buyer|biguint:100|u8:0|u64:1000";
+    let context = state.world.interpreter_context();
+    let mut account = Account::new().owner("address:owner").balance("100");
+    account.code = Some(BytesValue::interpret_from("mxsc:output/empty.mxsc.json", &context));
+    for (key, value) in [("str:lastOfferId", "u64:1"), (legacy_offer_key, legacy_offer)] {
+        account
+            .storage
+            .insert(BytesKey::interpret_from(key, &context), BytesValue::interpret_from(value, &context));
+    }
+    state
+        .world
+        .set_state_step(SetStateStep::new().put_account(legacy_escrow, account));
+
+    let raw = state
+        .world
+        .query()
+        .to(legacy_escrow)
+        .typed(EscrowContractProxy)
+        .get_offer(1u64)
+        .returns(ReturnsRawResult)
+        .run();
+    // View eski kaydı okuyup güncel kodlamada döndürür, eklenen alanlar varsayılan değerlerini alır
+    let offer = decode_offers(raw).pop().unwrap();
+    assert_eq!(offer.creator, SELLER.to_managed_address());
+    assert_eq!(offer.recipient, BUYER.to_managed_address());
+    assert_eq!(offer.amount, BigUint::from(100u64));
+    assert_eq!(offer.remaining_amount, BigUint::from(100u64));
+    assert_eq!(offer.created_timestamp, 1000);
+    assert_eq!(offer.closed_by, ManagedAddress::zero());
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(legacy_escrow)
+        .typed(EscrowContractProxy)
+        .upgrade()
+        .code(CODE_PATH)
+        .run();
+    let (legacy_count, remaining) = state
+        .world
+        .query()
+        .to(legacy_escrow)
+        .typed(EscrowContractProxy)
+        .get_migration_progress()
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(legacy_count, 1);
+    assert_eq!(remaining, 1);
+
+    let migrated = state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(legacy_escrow)
+        .typed(EscrowContractProxy)
+        .migrate_offers(1u64, 10u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(migrated, 1);
+
+    // Kayıt güncel kodlamada yeniden yazılır: versiyon byte'ı ve sonradan eklenen alanların boş değerleri
+    let migrated_offer = format!(
+        "u8:{}|{legacy_offer}|u32:0|u32:0|u32:0|u64:0|0x{}|biguint:100",
+        empty::OFFER_VERSION,
+        "00".repeat(32)
+    );
+    let mut check = CheckAccount::new().check_storage(legacy_offer_key, &migrated_offer);
+    if let CheckStorage::Equal(details) = &mut check.storage {
+        details.other_storages_allowed = true;
+    }
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(legacy_escrow, check));
+
+    let (_, remaining) = state
+        .world
+        .query()
+        .to(legacy_escrow)
+        .typed(EscrowContractProxy)
+        .get_migration_progress()
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(remaining, 0);
+}
+
+#[test]
+fn release_offer_test() {
+    let mut state = EscrowTestState::new();
+
+    state.create(SELLER, BUYER, 100);
+    state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .release_offer(1u64)
+        .returns(ExpectError(4, "E004: Not offer creator"))
+        .run();
+
+    state.release(SELLER, 1);
+
+    let offer = state.offer(1).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    assert_eq!(offer.closed_by, SELLER.to_managed_address());
+    state.check_balance(BUYER, START_BALANCE + 100);
+    state.world.check_account(ESCROW).balance(0);
+    state.accept_expect_err(BUYER, 1, "E003: Offer not active");
+}
+
+#[test]
+fn delivery_test() {
+    let mut state = EscrowTestState::new();
+    state.deploy_marketplace();
+
+    state.create(SELLER, BUYER, 50);
+    state.create(SELLER, MARKETPLACE, 100);
+
+    // Teslimat çağrısı sadece kontrat alıcılar için ve oluşturan tarafından tanımlanabilir
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_delivery_call(1u64, 5_000_000u64, "onEscrowDelivery", MultiValueVec::<Vec<u8>>::new())
+        .returns(ExpectError(4, "E026: Recipient is not a smart contract"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_delivery_call(2u64, 5_000_000u64, "", MultiValueVec::<Vec<u8>>::new())
+        .returns(ExpectError(4, "E027: Delivery endpoint is empty"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_delivery_call(2u64, 5_000_000u64, "onEscrowDelivery", MultiValueVec::<Vec<u8>>::new())
+        .returns(ExpectError(4, "E004: Not offer creator"))
+        .run();
+
+    state.set_delivery_call(SELLER, 2, 7);
+    state.retry_delivery_expect_err(SELLER, 2, "E028: Offer not pending delivery");
+
+    // Fonlar endpoint çağrısı ile teslim edilir ve teklif tamamlanır
+    let logs = state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .release_offer(2u64)
+        .returns(ReturnsLogs)
+        .run();
+
+    let offer = state.offer(2).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    assert_eq!(state.marketplace_received(7), 100);
+    state.world.check_account(MARKETPLACE).balance(100);
+    state.world.check_account(ESCROW).balance(50);
+    assert!(logs.iter().any(|log| log.topics[0] == b"releaseOffer"));
+    assert!(logs.iter().any(|log| log.topics[0] == b"deliveryCompleted"));
+
+    let reputation = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_user_reputation(SELLER)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reputation.completed_as_creator, 1);
+}
+
+#[test]
+fn delivery_failure_test() {
+    let mut state = EscrowTestState::new();
+    state.deploy_marketplace();
+    state.set_marketplace_rejecting(true);
+
+    state.create(SELLER, MARKETPLACE, 100);
+    state.set_delivery_call(SELLER, 1, 7);
+
+    // Çağrı başarısız olunca fonlar escrow'a döner ve teklif PendingDelivery durumunda kalır
+    let logs = state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .release_offer(1u64)
+        .returns(ReturnsLogs)
+        .run();
+
+    let offer = state.offer(1).unwrap();
+    assert!(offer.status == empty::OfferStatus::PendingDelivery);
+    assert_eq!(offer.closed_timestamp, 0);
+    assert!(logs.iter().any(|log| log.topics[0] == b"deliveryFailed"));
+    state.world.check_account(ESCROW).balance(100);
+    state.world.check_account(MARKETPLACE).balance(0);
+
+    let in_progress: bool = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .delivery_in_progress(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(!in_progress);
+
+    // Teslim bekleyen teklif iptal edilemez, tekrar serbest bırakılamaz ve arşivlenemez
+    state.cancel_expect_err(SELLER, 1, "E003: Offer not active");
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .release_offer(1u64)
+        .returns(ExpectError(4, "E003: Offer not active"))
+        .run();
+    state.world.current_block().block_timestamp(START_TIMESTAMP + 31 * DAY);
+    state.archive_expect_err(&[1], "E021: Offer not finished");
+    assert_eq!(state.user_closed_offer_ids(SELLER, 0, 10), Vec::<u64>::new());
+
+    // Alıcı kontrat düzeltildikten sonra taraflardan biri teslimatı tekrar deneyebilir
+    state.set_marketplace_rejecting(false);
+    state.retry_delivery_expect_err(OTHER, 1, "E030: Not offer creator or recipient");
+    state.retry_delivery(SELLER, 1);
+
+    let offer = state.offer(1).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    assert_eq!(offer.closed_timestamp, START_TIMESTAMP + 31 * DAY);
+    assert_eq!(state.marketplace_received(7), 100);
+    state.world.check_account(ESCROW).balance(0);
+    state.retry_delivery_expect_err(SELLER, 1, "E028: Offer not pending delivery");
+
+    // Teslimatı başarısız olmuş teklifin alıcısı engellenirse admin fonları oluşturana iade edebilir
+    state.set_marketplace_rejecting(true);
+    state.create(SELLER, MARKETPLACE, 50);
+    state.set_delivery_call(SELLER, 2, 8);
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .release_offer(2u64)
+        .run();
+    assert!(state.offer(2).unwrap().status == empty::OfferStatus::PendingDelivery);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .block_address(MARKETPLACE)
+        .run();
+    state.retry_delivery_expect_err(SELLER, 2, "E011: Recipient address is blocked");
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .admin_refund_offer(2u64)
+        .run();
+
+    assert!(state.offer(2).unwrap().status == empty::OfferStatus::Cancelled);
+    state.check_balance(SELLER, START_BALANCE - 100);
+    state.world.check_account(ESCROW).balance(0);
+    state.world.check_account(MARKETPLACE).balance(100);
+}
+
+#[test]
+fn conditional_release_test() {
+    let mut state = EscrowTestState::new();
+    state.deploy_dao();
+
+    // Koşulun hedefi bir kontrat olmalı ve view adı boş olmamalıdır
+    for condition in [
+        proposal_condition(OTHER.to_managed_address(), 7),
+        ReleaseCondition {
+            view: ManagedBuffer::new(),
+            ..proposal_condition(DAO.to_managed_address(), 7)
+        },
+    ] {
+        state
+            .world
+            .tx()
+            .from(SELLER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .create(
+                BUYER,
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+                OptionalValue::Some(0u64),
+                OptionalValue::Some(Some(condition)),
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .egld(100)
+            .returns(ExpectError(4, "E031: Invalid release condition"))
+            .run();
+    }
+
+    state.create(SELLER, BUYER, 50);
+    state.create_conditional(SELLER, BUYER, 100, proposal_condition(DAO.to_managed_address(), 7));
+
+    let condition = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_release_condition(2u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(condition.target, DAO.to_managed_address());
+    assert_eq!(condition.view, ManagedBuffer::from("isProposalPassed"));
+
+    state.try_release_expect_err(OTHER, 1, "E032: Offer has no release condition");
+    state.try_release_expect_err(OTHER, 2, "E033: Release condition not met");
+
+    // Koşul sağlandığında herkes fonları serbest bırakabilir
+    state.set_proposal_passed(7, true);
+    let logs = state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .try_release(2u64)
+        .returns(ReturnsLogs)
+        .run();
+
+    let offer = state.offer(2).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    assert_eq!(offer.closed_by, OTHER.to_managed_address());
+    assert!(logs.iter().any(|log| log.topics[0] == b"conditionalRelease"));
+    state.check_balance(BUYER, START_BALANCE + 100);
+    state.world.check_account(ESCROW).balance(50);
+    state.try_release_expect_err(OTHER, 2, "E003: Offer not active");
+}
+
+#[test]
+fn usd_offer_test() {
+    let mut state = EscrowTestState::new();
+    let usd_seller = TestAddress::new("usd-seller");
+    state.world.account(usd_seller).nonce(1).balance(8 * EGLD);
+
+    // Oracle ayarlanmadan USD cinsinden teklif oluşturulamaz
+    state
+        .world
+        .tx()
+        .from(usd_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create(
+            BUYER,
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(0u64),
+            OptionalValue::Some(None::<ReleaseCondition<StaticApi>>),
+            OptionalValue::Some(BigUint::<StaticApi>::from(10_000u64)),
+            OptionalValue::<bool>::None,
+        )
+        .egld(EGLD)
+        .returns(ExpectError(4, "E034: Price oracle not set"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_price_oracle(OTHER, MAX_PRICE_AGE)
+        .returns(ExpectError(4, "E035: Price oracle must be a contract in the same shard"))
+        .run();
+
+    state.deploy_price_aggregator();
+    state.set_egld_price(4_000);
+
+    // Fiyat yaşı sınırı sıfır veya bir günden uzun olamaz
+    for max_price_age in [0, empty::MAX_PRICE_AGE_LIMIT + 1] {
+        state
+            .world
+            .tx()
+            .from(OWNER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .set_price_oracle(PRICE_AGGREGATOR, max_price_age)
+            .returns(ExpectError(4, "E070: Max price age must be between 1 second and 1 day"))
+            .run();
+    }
+
+    // 100 USD için 4 EGLD kilitlenir, 40 USD fiyatla 2.5 EGLD ödenir ve 1.5 EGLD iade edilir
+    state.create_usd(usd_seller, BUYER, 4 * EGLD, 10_000);
+    state.create_usd(usd_seller, BUYER, 4 * EGLD, 10_000);
+
+    let logs = state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_offer(1u64, no_swap())
+        .returns(ReturnsLogs)
+        .run();
+    let log = logs.iter().find(|log| log.topics[0] == b"usdOfferSettled").unwrap();
+    assert_eq!(log.topics.len(), 2);
+    let data = empty_proxy::UsdOfferSettledEventData::<StaticApi>::top_decode(log.data[0].clone()).unwrap();
+    assert_eq!(data.usd_amount, BigUint::from(10_000u64));
+    assert_eq!(data.refund, BigUint::from(3 * EGLD / 2));
+
+    let offer = state.offer(1).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    assert_eq!(offer.amount, BigUint::from(5 * EGLD / 2));
+    state.check_balance(BUYER, START_BALANCE + 5 * EGLD / 2);
+    state.check_balance(usd_seller, 3 * EGLD / 2);
+    state.world.check_account(ESCROW).balance(4 * EGLD);
+
+    let stats = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_stats()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(stats.tokens.get(0).value_locked, BigUint::from(4 * EGLD));
+    assert_eq!(stats.tokens.get(0).volume, BigUint::from(5 * EGLD / 2));
+
+    // 10 USD fiyatla 10 EGLD gerekir, kilitli 4 EGLD yetmez ve teklif aktif kalır
+    state.set_egld_price(1_000);
+    state.accept_expect_err(BUYER, 2, "E037: Locked amount does not cover the USD amount");
+
+    // Güncellenmeyen fiyat kabul edilmez
+    state.set_egld_price(5_000);
+    state
+        .world
+        .current_block()
+        .block_timestamp(START_TIMESTAMP + MAX_PRICE_AGE + 1);
+    state.accept_expect_err(BUYER, 2, "E036: Oracle price is zero or stale");
+
+    // 50 USD fiyatla 2 EGLD ödenir ve 2 EGLD iade edilir
+    state.set_egld_price(5_000);
+    state.release(usd_seller, 2);
+    state.check_balance(BUYER, START_BALANCE + 9 * EGLD / 2);
+    state.check_balance(usd_seller, 7 * EGLD / 2);
+    state.world.check_account(ESCROW).balance(0);
+}
+
+#[test]
+fn swap_payout_test() {
+    let mut state = EscrowTestState::new();
+    state.create(SELLER, BUYER, 100);
+
+    // Takas yolu yalnızca yetkili adresler tarafından ve kontrat adresleriyle kurulabilir
+    state.accept_with_swap_expect_err(BUYER, 1, USDC, 0, "E038: No swap route for token");
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_swap_pair(USDC, OTHER)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_swap_pair(USDC, OTHER)
+        .returns(ExpectError(4, "E041: Swap contract must be a contract in the same shard"))
+        .run();
+
+    state.setup_swap_route();
+
+    // Kayma sınırı aşılırsa kabul geri alınır ve EGLD escrow'da kalır
+    state.accept_with_swap_expect_err(BUYER, 1, USDC, 301, "Slippage exceeded");
+    state.world.check_account(ESCROW).balance(100);
+
+    let logs = state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_offer(1u64, swap_to(USDC, 300))
+        .returns(ReturnsLogs)
+        .run();
+    assert!(logs.iter().any(|log| log.topics[0] == b"payoutSwapped"));
+
+    let offer = state.offer(1).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    state.world.check_account(BUYER).balance(START_BALANCE).esdt_balance(USDC, 300);
+    state.world.check_account(ESCROW).balance(0);
+    state.world.check_account(WRAPPER).balance(100);
+
+    // Kaldırılan pair ile takas yapılamaz
+    state.create(SELLER, BUYER, 100);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .remove_swap_pair(USDC)
+        .run();
+    state.accept_with_swap_expect_err(BUYER, 2, USDC, 0, "E038: No swap route for token");
+}
+
+#[test]
+fn liquid_staking_test() {
+    let mut state = EscrowTestState::new();
+
+    // Likit staking kontratı ayarlanmadan staking modunda teklif oluşturulamaz
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create(
+            BUYER,
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(ManagedBuffer::<StaticApi>::new()),
+            OptionalValue::Some(0u64),
+            OptionalValue::Some(None::<ReleaseCondition<StaticApi>>),
+            OptionalValue::Some(BigUint::<StaticApi>::zero()),
+            OptionalValue::Some(true),
+        )
+        .egld(100)
+        .returns(ExpectError(4, "E042: Liquid staking contract not set"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_liquid_staking(OTHER, 5_000u32)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_liquid_staking(OTHER, 5_000u32)
+        .returns(ExpectError(4, "E043: Liquid staking must be a contract in the same shard"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_liquid_staking(ESCROW, 10_001u32)
+        .returns(ExpectError(4, "E044: Yield share exceeds 10000 basis points"))
+        .run();
+
+    // Getirinin yarısı alıcıya, yarısı oluşturana
+    state.setup_liquid_staking(5_000);
+    state.create_staked(SELLER, BUYER, 100);
+    state.create_staked(SELLER, BUYER, 100);
+
+    // Kilitli EGLD likit staking kontratına yatırılır, escrow LST tutar
+    state.world.check_account(ESCROW).balance(0).esdt_balance(SEGLD, 200);
+    state.world.check_account(LIQUID_STAKING).balance(200);
+    let position = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_staking_position(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(position.liquid_staking, LIQUID_STAKING.to_managed_address());
+    assert_eq!(position.ls_amount, BigUint::from(100u64));
+    assert_eq!(position.recipient_yield_share, 5_000);
+
+    // Ayar değişse de açık pozisyonlar LST'nin alındığı kontrattan değerlenir
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_liquid_staking(ESCROW, 5_000u32)
+        .run();
+
+    // Ödeme LST olarak yapıldığı için takas desteklenmez
+    state.accept_with_swap_expect_err(BUYER, 1, USDC, 0, "E045: Not supported for staked offers");
+
+    // 1 LST = 1.2 EGLD: 100 LST'nin 83'ü anapara, 17'si getiri (alıcıya 8, oluşturana 9)
+    state.set_ls_exchange_rate(6, 5);
+    let logs = state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_offer(1u64, no_swap())
+        .returns(ReturnsLogs)
+        .run();
+    let log = logs.iter().find(|log| log.topics[0] == b"stakingPositionClosed").unwrap();
+    assert_eq!(log.topics.len(), 3);
+    let data = empty_proxy::StakingPositionClosedEventData::<StaticApi>::top_decode(log.data[0].clone()).unwrap();
+    assert_eq!(data.principal, BigUint::from(83u64));
+    assert_eq!(data.creator_yield, BigUint::from(9u64));
+    assert_eq!(data.recipient_yield, BigUint::from(8u64));
+    state.world.check_account(BUYER).balance(START_BALANCE).esdt_balance(SEGLD, 91);
+    state.world.check_account(SELLER).esdt_balance(SEGLD, 9);
+
+    // İptalde anapara oluşturana döner, getiri yine paylaştırılır
+    state.cancel(SELLER, 2);
+    state.world.check_account(SELLER).balance(START_BALANCE - 200).esdt_balance(SEGLD, 9 + 83 + 9);
+    state.world.check_account(BUYER).esdt_balance(SEGLD, 91 + 8);
+    state.world.check_account(ESCROW).esdt_balance(SEGLD, 0);
+    assert!(state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_staking_position(2u64)
+        .returns(ReturnsResult)
+        .run()
+        .is_none());
+
+    // Admin iadesinde getiri paylaştırılmaz, engellenmiş alıcıya LST gönderilmez
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_liquid_staking(LIQUID_STAKING, 5_000u32)
+        .run();
+    state.create_staked(SELLER, BUYER, 120);
+    state.set_ls_exchange_rate(3, 2);
+    state.block(OWNER, BUYER);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .admin_refund_offer(3u64)
+        .run();
+    state.world.check_account(SELLER).esdt_balance(SEGLD, 101 + 100);
+    state.world.check_account(BUYER).esdt_balance(SEGLD, 99);
+}
+
+#[test]
+fn auction_test() {
+    let mut state = EscrowTestState::new();
+    let nft_seller = TestAddress::new("nft-seller");
+    state
+        .world
+        .account(nft_seller)
+        .nonce(1)
+        .balance(START_BALANCE)
+        .esdt_nft_balance(NFT, 1, 1, ())
+        .esdt_nft_balance(NFT, 2, 1, ());
+
+    // İzin listesinde olmayan tokenlar açık artırmaya çıkarılamaz
+    state
+        .world
+        .tx()
+        .from(nft_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::English, 100u64, 0u64, DAY, OptionalValue::<u64>::None)
+        .single_esdt(&NFT.to_token_identifier(), 1, &BigUint::from(1u64))
+        .returns(ExpectError(4, "E071: Token not allowed"))
+        .run();
+    state.allow_token(NFT);
+
+    // English açık artırmada bitiş fiyatı verilmez, Dutch açık artırmada fiyat artamaz
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::English, 100u64, 5u64, DAY, OptionalValue::<u64>::None)
+        .egld(10)
+        .returns(ExpectError(4, "E046: Invalid auction parameters"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::Dutch, 100u64, 200u64, DAY, OptionalValue::<u64>::None)
+        .egld(10)
+        .returns(ExpectError(4, "E046: Invalid auction parameters"))
+        .run();
+
+    // English: NFT en düşük 100 EGLD teklifle bir günlüğüne açık artırmaya çıkarılır
+    state
+        .world
+        .tx()
+        .from(nft_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::English, 100u64, 0u64, DAY, OptionalValue::<u64>::None)
+        .single_esdt(&NFT.to_token_identifier(), 1, &BigUint::from(1u64))
+        .run();
+    state.world.check_account(ESCROW).esdt_nft_balance_and_attributes(NFT, 1, 1, Vec::<u8>::new());
+    let auction = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_auction(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert!(auction.kind == AuctionKind::English);
+    assert_eq!(auction.deadline, START_TIMESTAMP + DAY);
+
+    state.bid_expect_err(nft_seller, 1, 100, "E053: Creator cannot bid on own auction");
+    state.bid_expect_err(BUYER, 1, 99, "E051: Bid too low");
+    state.bid(BUYER, 1, 100);
+    state.bid_expect_err(OTHER, 1, 100, "E051: Bid too low");
+
+    // Geçilen teklif iade bakiyesine eklenir ve çekilene kadar kilitli kalır
+    // En yüksek teklifi veren teklifin alıcısı olur
+    state.bid(OTHER, 1, 150);
+    state.check_balance(BUYER, START_BALANCE - 100);
+    assert_eq!(state.pending_refund(BUYER), 100);
+    state.world.check_account(ESCROW).balance(250);
+    assert_eq!(state.egld_value_locked(), BigUint::from(250u64));
+
+    // Engellenen adres iadesini engel kaldırılana kadar çekemez
+    state.block(OWNER, BUYER);
+    state.withdraw_refund_expect_err(BUYER, "E011: Recipient address is blocked");
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .unblock_address(BUYER)
+        .run();
+
+    state.withdraw_refund(BUYER);
+    state.withdraw_refund_expect_err(BUYER, "E069: No refund to withdraw");
+    state.check_balance(BUYER, START_BALANCE);
+    assert_eq!(state.pending_refund(BUYER), 0);
+    state.world.check_account(ESCROW).balance(150);
+    assert_eq!(state.egld_value_locked(), BigUint::from(150u64));
+    assert_eq!(state.offer(1).unwrap().recipient, OTHER.to_managed_address());
+
+    // Teklif almış açık artırma iptal edilemez ve acceptOffer ile sonuçlandırılamaz
+    state.cancel_expect_err(nft_seller, 1, "E052: Auction has bids");
+    state.accept_expect_err(OTHER, 1, "E047: Not supported for auction offers");
+    state.settle_auction_expect_err(1, "E050: Auction has not ended");
+
+    state.world.current_block().block_timestamp(START_TIMESTAMP + DAY);
+    state.bid_expect_err(BUYER, 1, 200, "E049: Auction has ended");
+
+    let logs = state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .settle_auction(1u64)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(logs.iter().any(|log| log.topics[0] == b"acceptOffer"));
+    assert!(logs.iter().any(|log| log.topics[0] == b"auctionSettled"));
+    assert!(state.offer(1).unwrap().status == empty::OfferStatus::Completed);
+    state.world.check_account(OTHER).balance(START_BALANCE - 150).esdt_nft_balance_and_attributes(NFT, 1, 1, Vec::<u8>::new());
+    state.check_balance(nft_seller, START_BALANCE + 150);
+    assert_eq!(state.egld_value_locked(), BigUint::zero());
+
+    // Dutch: fiyat bir gün içinde 200'den 100'e düşer
+    state
+        .world
+        .tx()
+        .from(nft_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::Dutch, 200u64, 100u64, DAY, OptionalValue::<u64>::None)
+        .single_esdt(&NFT.to_token_identifier(), 2, &BigUint::from(1u64))
+        .run();
+    state.bid_expect_err(BUYER, 2, 200, "E048: Offer is not an auction of this kind");
+
+    state.world.current_block().block_timestamp(START_TIMESTAMP + DAY + DAY / 2);
+    let price = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_dutch_auction_price(2u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(price, BigUint::from(150u64));
+    state.buy_expect_err(BUYER, 2, 140, "E051: Bid too low");
+
+    // İlk ödeyen kazanır, fiyatın üzerindeki EGLD iade edilir
+    state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .buy(2u64)
+        .egld(160)
+        .run();
+    state.world.check_account(BUYER).balance(START_BALANCE - 150).esdt_nft_balance_and_attributes(NFT, 2, 1, Vec::<u8>::new());
+    state.check_balance(nft_seller, START_BALANCE + 300);
+    state.buy_expect_err(OTHER, 2, 200, "E003: Offer not active");
+
+    // Teklif almadan süresi dolan English açık artırmada varlık oluşturana döner
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::English, 5u64, 0u64, DAY, OptionalValue::<u64>::None)
+        .egld(10)
+        .run();
+    state.check_balance(SELLER, START_BALANCE - 10);
+    state.world.current_block().block_timestamp(START_TIMESTAMP + 3 * DAY);
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .settle_auction(3u64)
+        .run();
+    assert!(state.offer(3).unwrap().status == empty::OfferStatus::Cancelled);
+    state.check_balance(SELLER, START_BALANCE);
+    state.world.check_account(ESCROW).balance(0);
+
+    // Admin iadesinde en yüksek teklif de sahibinin iade bakiyesine eklenir
+    state
+        .world
+        .tx()
+        .from(SELLER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::English, 5u64, 0u64, DAY, OptionalValue::<u64>::None)
+        .egld(10)
+        .run();
+    state.bid(OTHER, 4, 20);
+    state.block(OWNER, SELLER);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .admin_refund_offer(4u64)
+        .run();
+    state.check_balance(SELLER, START_BALANCE);
+    assert_eq!(state.pending_refund(OTHER), 20);
+    state.withdraw_refund(OTHER);
+    state.check_balance(OTHER, START_BALANCE - 150);
+    state.world.check_account(ESCROW).balance(0);
+    assert_eq!(state.egld_value_locked(), BigUint::zero());
+}
+
+#[test]
+fn sealed_auction_test() {
+    let mut state = EscrowTestState::new();
+    let nft_seller = TestAddress::new("nft-seller");
+    state
+        .world
+        .account(nft_seller)
+        .nonce(1)
+        .balance(START_BALANCE)
+        .esdt_nft_balance(NFT, 1, 1, ());
+    state.allow_token(NFT);
+
+    // Açıklanmayan teminatın %10'u kesilir
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_unrevealed_bid_penalty(1_000u32)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_unrevealed_bid_penalty(10_001u32)
+        .returns(ExpectError(4, "E060: Penalty exceeds 10000 basis points"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_unrevealed_bid_penalty(1_000u32)
+        .run();
+
+    // Kapalı teklifli açık artırma için açıklama süresi verilmelidir
+    state
+        .world
+        .tx()
+        .from(nft_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::Sealed, 100u64, 0u64, DAY, OptionalValue::<u64>::None)
+        .single_esdt(&NFT.to_token_identifier(), 1, &BigUint::from(1u64))
+        .returns(ExpectError(4, "E046: Invalid auction parameters"))
+        .run();
+
+    // Bir gün teklif verme, bir gün açıklama süresi
+    state
+        .world
+        .tx()
+        .from(nft_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::Sealed, 100u64, 0u64, DAY, OptionalValue::Some(DAY))
+        .single_esdt(&NFT.to_token_identifier(), 1, &BigUint::from(1u64))
+        .run();
+
+    // Teminat teklifi gizlemek için tekliften fazla olabilir
+    state.commit_bid(BUYER, 1, 150, "buyer-salt", 200);
+    state.commit_bid_expect_err(BUYER, 1, 200, "E054: Bid already committed");
+    state.commit_bid_expect_err(nft_seller, 1, 200, "E053: Creator cannot bid on own auction");
+    state.commit_bid_expect_err(OTHER, 1, 50, "E051: Bid too low");
+    state.commit_bid(OTHER, 1, 120, "other-salt", 120);
+    // Teminatı aşan teklif açıklanamaz ve açıklanmamış sayılır
+    state.commit_bid(OWNER, 1, 500, "owner-salt", 400);
+    state.world.check_account(ESCROW).balance(720);
+    assert_eq!(state.egld_value_locked(), BigUint::from(720u64));
+
+    state.reveal_bid_expect_err(BUYER, 1, 150, "buyer-salt", "E056: Reveal window not open");
+    state.cancel_expect_err(nft_seller, 1, "E052: Auction has bids");
+    state.bid_expect_err(BUYER, 1, 200, "E048: Offer is not an auction of this kind");
+
+    state.world.current_block().block_timestamp(START_TIMESTAMP + DAY);
+    state.commit_bid_expect_err(SELLER, 1, 200, "E049: Auction has ended");
+    state.reveal_bid_expect_err(BUYER, 1, 150, "wrong-salt", "E058: Bid does not match commitment");
+    state.reveal_bid_expect_err(OWNER, 1, 500, "owner-salt", "E059: Bid exceeds deposit");
+    state.reveal_bid_expect_err(SELLER, 1, 150, "salt", "E057: No sealed bid to reveal");
+
+    // Öne geçen teklifin teminat fazlası, geride kalan teklifin teminatı iade bakiyesine eklenir
+    state.reveal_bid(BUYER, 1, 150, "buyer-salt");
+    assert_eq!(state.pending_refund(BUYER), 50);
+    state.reveal_bid(OTHER, 1, 120, "other-salt");
+    assert_eq!(state.pending_refund(OTHER), 120);
+    state.check_balance(OTHER, START_BALANCE - 120);
+    state.world.check_account(ESCROW).balance(720);
+    assert_eq!(state.egld_value_locked(), BigUint::from(720u64));
+    assert_eq!(state.offer(1).unwrap().recipient, BUYER.to_managed_address());
+
+    state.settle_auction_expect_err(1, "E050: Auction has not ended");
+    state.world.current_block().block_timestamp(START_TIMESTAMP + 2 * DAY);
+    state.reveal_bid_expect_err(OTHER, 1, 120, "other-salt", "E049: Auction has ended");
+
+    // En yüksek açıklanan teklif kazanır, açıklanmayan teminattan kesilen ceza oluşturana gider
+    let logs = state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .settle_auction(1u64)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(logs.iter().any(|log| log.topics[0] == b"unrevealedBidPenalized"));
+    assert!(logs.iter().any(|log| log.topics[0] == b"auctionSettled"));
+    state.world.check_account(BUYER).esdt_nft_balance_and_attributes(NFT, 1, 1, Vec::<u8>::new());
+    state.check_balance(nft_seller, START_BALANCE + 150 + 40);
+    assert_eq!(state.pending_refund(OWNER), 360);
+    state.world.check_account(ESCROW).balance(530);
+    assert_eq!(state.egld_value_locked(), BigUint::from(530u64));
+
+    // Her teklif veren iade bakiyesini kendisi çeker
+    for bidder in [BUYER, OTHER, OWNER] {
+        state.withdraw_refund(bidder);
+    }
+    state.check_balance(BUYER, START_BALANCE - 150);
+    state.check_balance(OTHER, START_BALANCE);
+    state.check_balance(OWNER, START_BALANCE - 40);
+    state.world.check_account(ESCROW).balance(0);
+    assert_eq!(state.egld_value_locked(), BigUint::zero());
+}
+
+#[test]
+fn partial_fill_test() {
+    let mut state = EscrowTestState::new();
+    let token_seller = TestAddress::new("token-seller");
+    state
+        .world
+        .account(token_seller)
+        .nonce(1)
+        .balance(START_BALANCE)
+        .esdt_balance(TOKEN, 2_000);
+    state.allow_token(TOKEN);
+
+    // En az alım miktarı satılan miktardan büyük olamaz
+    state
+        .world
+        .tx()
+        .from(token_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_sell_offer(300u64, 1_001u64)
+        .single_esdt(&TOKEN.to_token_identifier(), 0, &BigUint::from(1_000u64))
+        .returns(ExpectError(4, "E061: Invalid sell offer parameters"))
+        .run();
+
+    // 1000 token toplam 300 EGLD'ye, en az 100'lük parçalar halinde satılır
+    state.create_sell_offer(token_seller, 1_000, 300, 100);
+    state.world.check_account(ESCROW).esdt_balance(TOKEN, 1_000);
+    let offer = state.offer(1).unwrap();
+    assert_eq!(offer.remaining_amount, BigUint::from(1_000u64));
+    assert_eq!(offer.recipient, ManagedAddress::zero());
+
+    state.accept_partial_expect_err(token_seller, 1, 200, 60, "E064: Creator cannot fill own offer");
+    state.accept_partial_expect_err(BUYER, 1, 0, 0, "E065: Fill amount is zero or exceeds remaining amount");
+    state.accept_partial_expect_err(BUYER, 1, 1_100, 330, "E065: Fill amount is zero or exceeds remaining amount");
+    state.accept_partial_expect_err(BUYER, 1, 50, 15, "E066: Fill below minimum fill size");
+    state.accept_partial_expect_err(BUYER, 1, 200, 59, "E067: Payment below fill price");
+
+    // Ödeme fiyatla orantılıdır, fazlası iade edilir
+    let logs = state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_partial(1u64, 200u64)
+        .egld(70)
+        .returns(ReturnsLogs)
+        .run();
+    let log = logs.iter().find(|log| log.topics[0] == b"partialFill").unwrap();
+    assert_eq!(log.topics[1], top_encode_to_vec_u8_or_panic(&1u64));
+    assert_eq!(log.topics[2], BUYER.to_address().as_bytes());
+    assert_eq!(log.topics.len(), 3);
+    let data = empty_proxy::PartialFillEventData::<StaticApi>::top_decode(log.data[0].clone()).unwrap();
+    assert_eq!(data.amount, BigUint::from(200u64));
+    assert_eq!(data.price, BigUint::from(60u64));
+    assert_eq!(data.remaining_amount, BigUint::from(800u64));
+    assert!(!logs.iter().any(|log| log.topics[0] == b"acceptOffer"));
+    state.world.check_account(BUYER).balance(START_BALANCE - 60).esdt_balance(TOKEN, 200);
+    state.check_balance(token_seller, START_BALANCE + 60);
+
+    let offer = state.offer(1).unwrap();
+    assert!(offer.status == empty::OfferStatus::Active);
+    assert_eq!(offer.remaining_amount, BigUint::from(800u64));
+    assert_eq!(offer.recipient, BUYER.to_managed_address());
+
+    // Satış teklifleri acceptOffer ile kabul edilemez
+    state.accept_expect_err(BUYER, 1, "E063: Not supported for sell offers");
+
+    // Kalan miktar en az alım miktarının altına düşerse kalanın tamamı alınabilir
+    state.accept_partial(OTHER, 1, 750, 225);
+    state.accept_partial_expect_err(BUYER, 1, 40, 12, "E066: Fill below minimum fill size");
+    let fill_price = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_fill_price(1u64, 50u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(fill_price, BigUint::from(15u64));
+
+    // Son alım teklifi tamamlar
+    let logs = state
+        .world
+        .tx()
+        .from(BUYER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .accept_partial(1u64, 50u64)
+        .egld(15)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(logs.iter().any(|log| log.topics[0] == b"partialFill"));
+    assert!(logs.iter().any(|log| log.topics[0] == b"acceptOffer"));
+    let offer = state.offer(1).unwrap();
+    assert!(offer.status == empty::OfferStatus::Completed);
+    assert_eq!(offer.remaining_amount, BigUint::zero());
+    assert_eq!(offer.closed_by, BUYER.to_managed_address());
+    state.world.check_account(BUYER).balance(START_BALANCE - 75).esdt_balance(TOKEN, 250);
+    state.world.check_account(OTHER).balance(START_BALANCE - 225).esdt_balance(TOKEN, 750);
+    state.check_balance(token_seller, START_BALANCE + 300);
+    state.accept_partial_expect_err(OTHER, 1, 100, 30, "E003: Offer not active");
+
+    // Oluşturan satılmamış kalan miktarı iptal ederek geri alır
+    state.create_sell_offer(token_seller, 1_000, 300, 100);
+    state.accept_partial(BUYER, 2, 100, 30);
+    state.cancel(token_seller, 2);
+    state.world.check_account(token_seller).esdt_balance(TOKEN, 900);
+    state.world.check_account(ESCROW).esdt_balance(TOKEN, 0);
+    state.accept_partial_expect_err(BUYER, 2, 100, 30, "E003: Offer not active");
+
+    // Alıcı itibarı parça sayısından bağımsız olarak teklif başına bir kez artar
+    let reputation = |state: &mut EscrowTestState, user: TestAddress| {
+        state
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .get_user_reputation(user)
+            .returns(ReturnsResult)
+            .run()
+    };
+    assert_eq!(reputation(&mut state, BUYER).accepted_as_recipient, 2);
+    assert_eq!(reputation(&mut state, OTHER).accepted_as_recipient, 1);
+    assert_eq!(reputation(&mut state, token_seller).completed_as_creator, 1);
+
+    let stats = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_stats()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(stats.completed_count, 1);
+    assert_eq!(stats.cancelled_count, 1);
+    assert_eq!(stats.tokens.get(0).value_locked, BigUint::zero());
+    assert_eq!(stats.tokens.get(0).volume, BigUint::from(1_100u64));
+}
+
+#[test]
+fn sell_offer_archive_test() {
+    let mut state = EscrowTestState::new();
+    let token_seller = TestAddress::new("token-seller");
+    state
+        .world
+        .account(token_seller)
+        .nonce(1)
+        .balance(START_BALANCE)
+        .esdt_balance(TOKEN, 1_000);
+    state.allow_token(TOKEN);
+
+    // Teklifin recipient alanında sadece son alıcı (OTHER) kalır
+    state.create_sell_offer(token_seller, 1_000, 300, 100);
+    state.accept_partial(BUYER, 1, 200, 60);
+    state.accept_partial(OTHER, 1, 800, 240);
+    assert_eq!(state.offer(1).unwrap().recipient, OTHER.to_managed_address());
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_archive_retention_period(0u64)
+        .run();
+    state.archive(&[1]);
+
+    // İlk alıcının listelerinde de arşivlenen teklif kalmaz
+    for buyer in [BUYER, OTHER] {
+        let incoming: Vec<u64> = state
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .user_incoming_offers(buyer)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+        assert!(incoming.is_empty());
+
+        let raw = state
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .get_user_incoming_active_offers(buyer)
+            .returns(ReturnsRawResult)
+            .run();
+        assert!(decode_offers(raw).is_empty());
+
+        let raw = state
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .get_user_incoming_closed_offers(buyer, 0usize, 10usize)
+            .returns(ReturnsRawResult)
+            .run();
+        assert!(decode_offers(raw).is_empty());
+    }
+}
+
+#[test]
+fn token_restrictions_test() {
+    let mut state = EscrowTestState::new();
+    let token_seller = TestAddress::new("token-seller");
+    state
+        .world
+        .account(token_seller)
+        .nonce(1)
+        .balance(START_BALANCE)
+        .esdt_balance(TOKEN, 2_000);
+
+    // Yapılandırma sadece Admin rolüyle değiştirilebilir
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .allow_token(TOKEN)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OTHER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_token_limits(EgldOrEsdtTokenIdentifier::egld(), 10u64, 0u64)
+        .returns(ExpectError(4, "E015: Caller does not have the required role"))
+        .run();
+
+    // İzin listesinde olmayan ESDT satışa çıkarılamaz
+    state
+        .world
+        .tx()
+        .from(token_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_sell_offer(300u64, 100u64)
+        .single_esdt(&TOKEN.to_token_identifier(), 0, &BigUint::from(1_000u64))
+        .returns(ExpectError(4, "E071: Token not allowed"))
+        .run();
+
+    state.allow_token(TOKEN);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .allow_token(TOKEN)
+        .returns(ExpectError(4, "E072: Token already allowed"))
+        .run();
+    let allowed: Vec<TokenIdentifier<StaticApi>> = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .allowed_tokens()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(allowed, vec![TOKEN.to_token_identifier()]);
+
+    // En az miktar en çok miktardan büyük olamaz, en çok miktar 0 ise üst sınır yoktur
+    let set_limits = |state: &mut EscrowTestState, token: EgldOrEsdtTokenIdentifier<StaticApi>, min: u64, max: u64| {
+        state
+            .world
+            .tx()
+            .from(OWNER)
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .set_token_limits(token, min, max)
+            .run();
+    };
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .set_token_limits(EgldOrEsdtTokenIdentifier::egld(), 100u64, 10u64)
+        .returns(ExpectError(4, "E073: Invalid token limits"))
+        .run();
+    set_limits(&mut state, EgldOrEsdtTokenIdentifier::esdt(TOKEN), 500, 1_000);
+    set_limits(&mut state, EgldOrEsdtTokenIdentifier::egld(), 10, 0);
+
+    let limits: Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, empty_proxy::TokenLimits<StaticApi>)> = state
+        .world
+        .query()
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .get_token_limits()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|limit| limit.into_tuple())
+        .collect();
+    assert_eq!(limits.len(), 2);
+    assert_eq!(limits[0].0, EgldOrEsdtTokenIdentifier::esdt(TOKEN));
+    assert_eq!(limits[0].1.min_amount, BigUint::from(500u64));
+    assert_eq!(limits[0].1.max_amount, BigUint::from(1_000u64));
+    assert_eq!(limits[1].0, EgldOrEsdtTokenIdentifier::egld());
+
+    // Sınırlar satış teklifleri, açık artırmalar ve EGLD teklifleri için uygulanır
+    state
+        .world
+        .tx()
+        .from(token_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_sell_offer(300u64, 100u64)
+        .single_esdt(&TOKEN.to_token_identifier(), 0, &BigUint::from(400u64))
+        .returns(ExpectError(4, "E075: Amount below token minimum"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(token_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_auction(AuctionKind::English, 100u64, 0u64, DAY, OptionalValue::<u64>::None)
+        .single_esdt(&TOKEN.to_token_identifier(), 0, &BigUint::from(1_001u64))
+        .returns(ExpectError(4, "E076: Amount above token maximum"))
+        .run();
+    state.create_sell_offer(token_seller, 1_000, 300, 100);
+    state.create_expect_err(SELLER, BUYER, 9, "E075: Amount below token minimum");
+    state.create(SELLER, BUYER, 500);
+
+    // Sınırlar kaldırıldıktan sonra uygulanmaz, izin listesinden çıkarılan token tekrar kabul edilmez
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .remove_token_limits(EgldOrEsdtTokenIdentifier::egld())
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .remove_token_limits(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectError(4, "E074: Token limits not set"))
+        .run();
+    state.create(SELLER, BUYER, 9);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .remove_allowed_token(TOKEN)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .remove_allowed_token(TOKEN)
+        .returns(ExpectError(4, "E071: Token not allowed"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(token_seller)
+        .to(ESCROW)
+        .typed(EscrowContractProxy)
+        .create_sell_offer(300u64, 100u64)
+        .single_esdt(&TOKEN.to_token_identifier(), 0, &BigUint::from(1_000u64))
+        .returns(ExpectError(4, "E071: Token not allowed"))
+        .run();
+
+    // Mevcut teklif izin listesinden çıkarılmadan etkilenmez
+    state.accept_partial(BUYER, 1, 1_000, 300);
+    state.world.check_account(BUYER).esdt_balance(TOKEN, 1_000);
+}
@@ -0,0 +1,356 @@
+use empty::empty_proxy::{EscrowContractProxy, ReleaseCondition};
+use empty::{Offer, OfferStatus};
+use multiversx_sc_scenario::imports::*;
+use std::panic::{self, AssertUnwindSafe};
+
+const CODE_PATH: MxscPath = MxscPath::new("output/empty.mxsc.json");
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const USERS: [TestAddress; 4] = [
+    TestAddress::new("user-a"),
+    TestAddress::new("user-b"),
+    TestAddress::new("user-c"),
+    TestAddress::new("user-d"),
+];
+const ESCROW: TestSCAddress = TestSCAddress::new("escrow");
+
+const START_BALANCE: u64 = 1_000_000;
+const MAX_AMOUNT: u64 = 1_000;
+const STEPS_PER_RUN: usize = 150;
+const SEEDS: [u64; 4] = [1, 42, 1_337, 0xDEAD_BEEF];
+
+// Testlerin tekrarlanabilir olması için sabit tohumlu basit bir rastgele sayı üreteci (xorshift64)
+// Hata veren bir çalıştırma aynı tohumla birebir tekrar edilebilir
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn user(&mut self) -> usize {
+        self.below(USERS.len() as u64) as usize
+    }
+}
+
+// Kontratın durumunun test tarafındaki modeli
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum ModelStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+struct ModelOffer {
+    creator: usize,
+    recipient: usize,
+    amount: u64,
+    status: ModelStatus,
+}
+
+enum Action {
+    Create { from: usize, to: usize, amount: u64 },
+    Accept { from: usize, offer_id: u64 },
+    Cancel { from: usize, offer_id: u64 },
+}
+
+struct InvariantState {
+    world: ScenarioWorld,
+    offers: Vec<ModelOffer>,
+    balances: [u64; 4],
+}
+
+impl InvariantState {
+    fn new() -> Self {
+        let mut world = ScenarioWorld::new();
+        world.register_contract(CODE_PATH, empty::ContractBuilder);
+
+        world.account(OWNER).nonce(1);
+        for user in USERS {
+            world.account(user).nonce(1).balance(START_BALANCE);
+        }
+
+        world
+            .tx()
+            .from(OWNER)
+            .typed(EscrowContractProxy)
+            .init()
+            .code(CODE_PATH)
+            .new_address(ESCROW)
+            .run();
+
+        Self {
+            world,
+            offers: Vec::new(),
+            balances: [START_BALANCE; 4],
+        }
+    }
+
+    // Geçerli ve geçersiz çağrıları karışık üretir
+    // ID'ler bazen mevcut teklif sayısının dışından seçilir, miktar bazen 0 olur
+    fn random_action(&self, rng: &mut Rng) -> Action {
+        let offer_id = rng.below(self.offers.len() as u64 + 2);
+        match rng.below(3) {
+            0 => Action::Create {
+                from: rng.user(),
+                to: rng.user(),
+                amount: if rng.below(10) == 0 { 0 } else { 1 + rng.below(MAX_AMOUNT) },
+            },
+            1 => {
+                // Çoğunlukla doğru alıcıyı seçiyoruz, aksi halde kabul neredeyse hiç başarılı olmaz
+                let from = match self.model_offer(offer_id) {
+                    Some(offer) if rng.below(4) != 0 => offer.recipient,
+                    _ => rng.user(),
+                };
+                Action::Accept { from, offer_id }
+            },
+            _ => {
+                let from = match self.model_offer(offer_id) {
+                    Some(offer) if rng.below(4) != 0 => offer.creator,
+                    _ => rng.user(),
+                };
+                Action::Cancel { from, offer_id }
+            },
+        }
+    }
+
+    fn model_offer(&self, offer_id: u64) -> Option<&ModelOffer> {
+        if offer_id == 0 {
+            return None;
+        }
+        self.offers.get(offer_id as usize - 1)
+    }
+
+    // Modele göre beklenen hatayı hesaplar, kontrattaki kontrol sırasıyla aynıdır
+    fn expected_error(&self, action: &Action) -> Option<&'static str> {
+        match *action {
+            Action::Create { amount: 0, .. } => Some("E001: Must pay more than 0"),
+            Action::Create { from, to, .. } => (from == to).then_some("E068: Creator cannot be the recipient"),
+            Action::Accept { from, offer_id } => match self.model_offer(offer_id) {
+                None => Some("E002: Offer not found"),
+                Some(offer) if offer.status != ModelStatus::Active => Some("E003: Offer not active"),
+                Some(offer) if offer.recipient != from => Some("E005: Not offer recipient"),
+                Some(_) => None,
+            },
+            Action::Cancel { from, offer_id } => match self.model_offer(offer_id) {
+                None => Some("E002: Offer not found"),
+                Some(offer) if offer.status != ModelStatus::Active => Some("E003: Offer not active"),
+                Some(offer) if offer.creator != from => Some("E004: Not offer creator"),
+                Some(_) => None,
+            },
+        }
+    }
+
+    fn execute(&mut self, action: &Action) {
+        let expected_error = self.expected_error(action);
+
+        match *action {
+            Action::Create { from, to, amount } => {
+                let tx = self
+                    .world
+                    .tx()
+                    .from(USERS[from])
+                    .to(ESCROW)
+                    .typed(EscrowContractProxy)
+                    .create(
+                        USERS[to],
+                        OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                        OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                        OptionalValue::<ManagedBuffer<StaticApi>>::None,
+                        OptionalValue::<u64>::None,
+                        OptionalValue::<Option<ReleaseCondition<StaticApi>>>::None,
+                        OptionalValue::<BigUint<StaticApi>>::None,
+                        OptionalValue::<bool>::None,
+                    )
+                    .egld(amount);
+                match expected_error {
+                    Some(err) => tx.returns(ExpectError(4, err)).run(),
+                    None => {
+                        tx.run();
+                        self.balances[from] -= amount;
+                        self.offers.push(ModelOffer {
+                            creator: from,
+                            recipient: to,
+                            amount,
+                            status: ModelStatus::Active,
+                        });
+                    },
+                }
+            },
+            Action::Accept { from, offer_id } => {
+                let tx = self
+                    .world
+                    .tx()
+                    .from(USERS[from])
+                    .to(ESCROW)
+                    .typed(EscrowContractProxy)
+                    .accept_offer(
+                        offer_id,
+                        OptionalValue::<MultiValue2<TokenIdentifier<StaticApi>, BigUint<StaticApi>>>::None,
+                    );
+                match expected_error {
+                    Some(err) => tx.returns(ExpectError(4, err)).run(),
+                    None => {
+                        tx.run();
+                        let offer = &mut self.offers[offer_id as usize - 1];
+                        offer.status = ModelStatus::Completed;
+                        self.balances[from] += offer.amount;
+                    },
+                }
+            },
+            Action::Cancel { from, offer_id } => {
+                let tx = self
+                    .world
+                    .tx()
+                    .from(USERS[from])
+                    .to(ESCROW)
+                    .typed(EscrowContractProxy)
+                    .cancel_offer(offer_id);
+                match expected_error {
+                    Some(err) => tx.returns(ExpectError(4, err)).run(),
+                    None => {
+                        tx.run();
+                        let offer = &mut self.offers[offer_id as usize - 1];
+                        offer.status = ModelStatus::Cancelled;
+                        self.balances[from] += offer.amount;
+                    },
+                }
+            },
+        }
+    }
+
+    fn check_invariants(&mut self, previous_statuses: &[ModelStatus]) {
+        // Kontrat bakiyesi aktif tekliflerin toplamına eşit olmalıdır
+        let locked: u64 = self
+            .offers
+            .iter()
+            .filter(|offer| offer.status == ModelStatus::Active)
+            .map(|offer| offer.amount)
+            .sum();
+        self.world.check_account(ESCROW).balance(locked);
+
+        // Hiçbir fon kaybolmamalı veya iki kez harcanmamalıdır
+        for (index, user) in USERS.iter().enumerate() {
+            self.world.check_account(*user).balance(self.balances[index]);
+        }
+        assert_eq!(
+            self.balances.iter().sum::<u64>() + locked,
+            START_BALANCE * USERS.len() as u64
+        );
+
+        // Her teklif ID'si sadece oluşturanın ve alıcının listelerinde yer almalıdır
+        for (index, user) in USERS.iter().enumerate() {
+            let expected_created: Vec<u64> = self.offer_ids_where(|offer| offer.creator == index);
+            let expected_incoming: Vec<u64> = self.offer_ids_where(|offer| offer.recipient == index);
+
+            let mut created: Vec<u64> = self
+                .world
+                .query()
+                .to(ESCROW)
+                .typed(EscrowContractProxy)
+                .user_offers(*user)
+                .returns(ReturnsResult)
+                .run()
+                .into_iter()
+                .collect();
+            created.sort_unstable();
+            assert_eq!(created, expected_created);
+
+            let mut incoming: Vec<u64> = self
+                .world
+                .query()
+                .to(ESCROW)
+                .typed(EscrowContractProxy)
+                .user_incoming_offers(*user)
+                .returns(ReturnsResult)
+                .run()
+                .into_iter()
+                .collect();
+            incoming.sort_unstable();
+            assert_eq!(incoming, expected_incoming);
+        }
+
+        // Zincirdeki durum modelle aynı olmalı, sonuçlanmış teklifin durumu bir daha değişmemelidir
+        let offer_count = self.offers.len();
+        for offer_id in 1..=offer_count as u64 {
+            let status = to_model_status(&self.offer(offer_id).status);
+            let expected = self.offers[offer_id as usize - 1].status;
+            assert_eq!(status, expected, "offer {offer_id}");
+
+            if let Some(previous) = previous_statuses.get(offer_id as usize - 1) {
+                if *previous != ModelStatus::Active {
+                    assert_eq!(status, *previous, "offer {offer_id} changed after it was closed");
+                }
+            }
+        }
+    }
+
+    fn offer_ids_where(&self, predicate: impl Fn(&ModelOffer) -> bool) -> Vec<u64> {
+        self.offers
+            .iter()
+            .enumerate()
+            .filter(|(_, offer)| predicate(offer))
+            .map(|(index, _)| index as u64 + 1)
+            .collect()
+    }
+
+    fn offer(&mut self, offer_id: u64) -> Offer<StaticApi> {
+        let raw = self
+            .world
+            .query()
+            .to(ESCROW)
+            .typed(EscrowContractProxy)
+            .get_offer(offer_id)
+            .returns(ReturnsRawResult)
+            .run();
+
+        Offer::top_decode(raw.get(0).clone()).unwrap()
+    }
+}
+
+fn to_model_status(status: &OfferStatus) -> ModelStatus {
+    match status {
+        OfferStatus::Active => ModelStatus::Active,
+        OfferStatus::Completed => ModelStatus::Completed,
+        OfferStatus::Cancelled => ModelStatus::Cancelled,
+        OfferStatus::PendingDelivery => panic!("offers without a delivery call never wait for delivery"),
+    }
+}
+
+fn run_random_sequence(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut state = InvariantState::new();
+
+    for step in 0..STEPS_PER_RUN {
+        let action = state.random_action(&mut rng);
+        let previous_statuses: Vec<ModelStatus> = state.offers.iter().map(|offer| offer.status).collect();
+
+        // Hata mesajına tohum ve adım eklenir, böylece hata veren dizi aynı tohumla tekrar edilebilir
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            state.execute(&action);
+            state.check_invariants(&previous_statuses);
+        }));
+        if let Err(cause) = result {
+            let message = cause
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| cause.downcast_ref::<&str>().copied())
+                .unwrap_or("unknown panic");
+            panic!("seed {seed}, step {step}: {message}");
+        }
+    }
+}
+
+#[test]
+fn random_sequences_keep_invariants() {
+    for seed in SEEDS {
+        run_random_sequence(seed);
+    }
+}
@@ -1,10 +1,91 @@
 use multiversx_sc_scenario::*;
+use std::{fs, path::Path, time::SystemTime};
+
+// Go VM testleri derlenmiş kontratı ve mx-scenario-go aracını gerektirir
+// - sc-meta all build
+// - cargo test --features run-go-tests --test empty_scenario_go_test
+// Özellik verilmediğinde testler sessizce geçmek yerine ignored olarak görünür,
+// özellik verildiğinde araç yoksa veya derleme kaynak koddan eskiyse test başarısız olur
+
+const MXSC_FILE: &str = "output/empty.mxsc.json";
+const SOURCE_DIR: &str = "src";
 
 fn world() -> ScenarioWorld {
+    assert_build_is_fresh();
     ScenarioWorld::vm_go()
 }
 
+// Eski bir derleme ile Go VM sonuçları kaynak kodu yansıtmaz
+fn assert_build_is_fresh() {
+    let built = fs::metadata(MXSC_FILE)
+        .and_then(|metadata| metadata.modified())
+        .unwrap_or_else(|_| panic!("{MXSC_FILE} is missing, run `sc-meta all build` first"));
+    let source = latest_modification(Path::new(SOURCE_DIR));
+    assert!(
+        built >= source,
+        "{MXSC_FILE} is older than the sources in {SOURCE_DIR}/, run `sc-meta all build` again"
+    );
+}
+
+fn latest_modification(dir: &Path) -> SystemTime {
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                latest_modification(&path)
+            } else {
+                fs::metadata(&path).and_then(|metadata| metadata.modified()).unwrap()
+            }
+        })
+        .max()
+        .unwrap_or(SystemTime::UNIX_EPOCH)
+}
+
 #[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
 fn empty_go() {
     world().run("scenarios/empty.scen.json");
 }
+
+#[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
+fn accept_go() {
+    world().run("scenarios/accept.scen.json");
+}
+
+#[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
+fn accept_after_cancel_go() {
+    world().run("scenarios/accept_after_cancel.scen.json");
+}
+
+#[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
+fn accept_twice_go() {
+    world().run("scenarios/accept_twice.scen.json");
+}
+
+#[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
+fn cancel_go() {
+    world().run("scenarios/cancel.scen.json");
+}
+
+#[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
+fn cancel_not_creator_go() {
+    world().run("scenarios/cancel_not_creator.scen.json");
+}
+
+#[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
+fn create_go() {
+    world().run("scenarios/create.scen.json");
+}
+
+#[test]
+#[cfg_attr(not(feature = "run-go-tests"), ignore)]
+fn views_go() {
+    world().run("scenarios/views.scen.json");
+}
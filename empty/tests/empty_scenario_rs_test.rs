@@ -12,3 +12,38 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/empty.scen.json");
 }
+
+#[test]
+fn accept_rs() {
+    world().run("scenarios/accept.scen.json");
+}
+
+#[test]
+fn accept_after_cancel_rs() {
+    world().run("scenarios/accept_after_cancel.scen.json");
+}
+
+#[test]
+fn accept_twice_rs() {
+    world().run("scenarios/accept_twice.scen.json");
+}
+
+#[test]
+fn cancel_rs() {
+    world().run("scenarios/cancel.scen.json");
+}
+
+#[test]
+fn cancel_not_creator_rs() {
+    world().run("scenarios/cancel_not_creator.scen.json");
+}
+
+#[test]
+fn create_rs() {
+    world().run("scenarios/create.scen.json");
+}
+
+#[test]
+fn views_rs() {
+    world().run("scenarios/views.scen.json");
+}
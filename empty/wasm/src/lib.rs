@@ -5,9 +5,11 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           10
+// Upgrade:                              1
+// Endpoints:                           78
 // Async Callback (empty):               1
-// Total number of exported functions:  12
+// Promise callbacks:                    1
+// Total number of exported functions:  82
 
 #![no_std]
 
@@ -18,16 +20,86 @@ multiversx_sc_wasm_adapter::endpoints! {
     empty
     (
         init => init
+        upgrade => upgrade
         create => create
         cancelOffer => cancel_offer
         acceptOffer => accept_offer
+        releaseOffer => release_offer
+        tryRelease => try_release
+        setPriceOracle => set_price_oracle
+        allowToken => allow_token
+        removeAllowedToken => remove_allowed_token
+        setTokenLimits => set_token_limits
+        removeTokenLimits => remove_token_limits
+        setEgldWrapper => set_egld_wrapper
+        setSwapPair => set_swap_pair
+        removeSwapPair => remove_swap_pair
+        setLiquidStaking => set_liquid_staking
+        createAuction => create_auction
+        bid => bid
+        buy => buy
+        settleAuction => settle_auction
+        commitBid => commit_bid
+        revealBid => reveal_bid
+        withdrawRefund => withdraw_refund
+        setUnrevealedBidPenalty => set_unrevealed_bid_penalty
+        createSellOffer => create_sell_offer
+        acceptPartial => accept_partial
+        setDeliveryCall => set_delivery_call
+        retryDelivery => retry_delivery
+        migrateOffers => migrate_offers
+        archiveOffers => archive_offers
+        setArchiveRetentionPeriod => set_archive_retention_period
+        grantRole => grant_role
+        revokeRole => revoke_role
+        acceptAdminRole => accept_admin_role
+        blockAddress => block_address
+        unblockAddress => unblock_address
+        adminRefundOffer => admin_refund_offer
         getLastOfferId => last_offer_id
-        getOffer => offer
         getUserOffers => user_offers
         getUserIncomingOffers => user_incoming_offers
+        getBlockedAddresses => blocked_addresses
+        getRoleHolders => role_holders
+        getPendingAdmins => pending_admins
+        getArchivedOffer => archived_offer
+        getArchiveRetentionPeriod => archive_retention_period
+        getUsdAmount => usd_amount
+        getMaxPriceAge => max_price_age
+        getAllowedTokens => allowed_tokens
+        getRecipientYieldShare => recipient_yield_share
+        getHighestBid => highest_bid
+        getPendingRefund => pending_refunds
+        getUnrevealedBidPenalty => unrevealed_bid_penalty
+        isDeliveryInProgress => delivery_in_progress
+        getOffer => get_offer
+        getDeliveryCall => get_delivery_call
+        getReleaseCondition => get_release_condition
+        getPriceOracle => get_price_oracle
+        getEgldWrapper => get_egld_wrapper
+        getSwapPairs => get_swap_pairs
+        getTokenLimits => get_token_limits
+        getLiquidStaking => get_liquid_staking
+        getStakingPosition => get_staking_position
+        getAuction => get_auction
+        computeBidHash => compute_bid_hash
+        getSealedBids => get_sealed_bids
+        getDutchAuctionPrice => get_dutch_auction_price
+        getSellOffer => get_sell_offer
+        getFillPrice => get_fill_price
+        getStats => get_stats
         getActiveOffers => get_active_offers
         getUserActiveOffers => get_user_active_offers
         getUserIncomingActiveOffers => get_user_incoming_active_offers
+        getErrorCatalogue => get_error_catalogue
+        getMigrationProgress => get_migration_progress
+        hasRole => has_role
+        isAddressBlocked => is_address_blocked
+        getUserReputation => get_user_reputation
+        getOfferByReference => get_offer_by_reference
+        getUserClosedOffers => get_user_closed_offers
+        getUserIncomingClosedOffers => get_user_incoming_closed_offers
+        delivery_callback => delivery_callback
     )
 }
 